@@ -0,0 +1,198 @@
+//! Memory barriers between incoherent memory accesses.
+//!
+//! Writes issued through image load / store, shader storage blocks or atomic counters are not automatically
+//! visible to subsequent commands. Each barrier type below corresponds to one way in which such writes can be
+//! consumed and maps to a single bit of `glMemoryBarrier`. Barriers can be combined using (possibly nested) tuples
+//! e.g. `(ShaderStorage, (VertexAttribArray, Command))`.
+//!
+//! ## Tracking
+//!
+//! In debug builds incoherent writes can be recorded with `written`. Draw calls then check whether any of the
+//! memory paths they consume still has pending writes and panic if no barrier was issued in between.
+
+use std::cell::Cell;
+
+use crate::gl::{self, impl_token};
+
+/// Type level bitfield of `glMemoryBarrier` flags.
+pub trait Barrier {
+    const ID: u32;
+}
+
+/// Barriers that are accepted by `glMemoryBarrierByRegion`.
+#[hi::marker]
+pub trait ByRegion: Barrier { }
+
+/// Vertex data sourced from buffer objects.
+pub enum VertexAttribArray { }
+
+/// Vertex indices sourced from buffer objects.
+pub enum ElementArray { }
+
+/// Shader uniforms sourced from buffer objects.
+#[hi::mark(ByRegion)]
+pub enum Uniform { }
+
+/// Texture fetches from shaders, including buffer textures.
+#[hi::mark(ByRegion)]
+pub enum TextureFetch { }
+
+/// Image load, store and atomic operations.
+#[hi::mark(ByRegion)]
+pub enum ShaderImageAccess { }
+
+/// Indirect draw and dispatch commands sourced from buffer objects.
+pub enum Command { }
+
+/// Pixel transfers through pixel pack and unpack buffers.
+pub enum PixelBuffer { }
+
+/// Texture uploads, downloads and copies.
+pub enum TextureUpdate { }
+
+/// Buffer object updates, copies and mappings.
+pub enum BufferUpdate { }
+
+/// Access to persistently mapped buffers from the client.
+pub enum ClientMappedBuffer { }
+
+/// Writes to query buffer objects.
+pub enum QueryBuffer { }
+
+/// Reads and writes through framebuffer attachments.
+#[hi::mark(ByRegion)]
+pub enum Framebuffer { }
+
+/// Writes performed by transform feedback.
+pub enum TransformFeedback { }
+
+/// Atomic counter buffer accesses.
+#[hi::mark(ByRegion)]
+pub enum AtomicCounter { }
+
+/// Shader storage block accesses.
+#[hi::mark(ByRegion)]
+pub enum ShaderStorage { }
+
+/// All of the above.
+#[hi::mark(ByRegion)]
+pub enum All { }
+
+impl_token! { VertexAttribArray  as Barrier => VERTEX_ATTRIB_ARRAY_BARRIER_BIT  }
+impl_token! { ElementArray       as Barrier => ELEMENT_ARRAY_BARRIER_BIT        }
+impl_token! { Uniform            as Barrier => UNIFORM_BARRIER_BIT              }
+impl_token! { TextureFetch       as Barrier => TEXTURE_FETCH_BARRIER_BIT        }
+impl_token! { ShaderImageAccess  as Barrier => SHADER_IMAGE_ACCESS_BARRIER_BIT  }
+impl_token! { Command            as Barrier => COMMAND_BARRIER_BIT              }
+impl_token! { PixelBuffer        as Barrier => PIXEL_BUFFER_BARRIER_BIT         }
+impl_token! { TextureUpdate      as Barrier => TEXTURE_UPDATE_BARRIER_BIT       }
+impl_token! { BufferUpdate       as Barrier => BUFFER_UPDATE_BARRIER_BIT        }
+impl_token! { ClientMappedBuffer as Barrier => CLIENT_MAPPED_BUFFER_BARRIER_BIT }
+impl_token! { QueryBuffer        as Barrier => QUERY_BUFFER_BARRIER_BIT         }
+impl_token! { Framebuffer        as Barrier => FRAMEBUFFER_BARRIER_BIT          }
+impl_token! { TransformFeedback  as Barrier => TRANSFORM_FEEDBACK_BARRIER_BIT   }
+impl_token! { AtomicCounter      as Barrier => ATOMIC_COUNTER_BARRIER_BIT       }
+impl_token! { ShaderStorage      as Barrier => SHADER_STORAGE_BARRIER_BIT       }
+impl_token! { All                as Barrier => ALL_BARRIER_BITS                 }
+
+impl Barrier for () {
+    const ID: u32 = 0;
+}
+
+impl<H, T> Barrier for (H, T)
+where
+    H: Barrier,
+    T: Barrier,
+{
+    const ID: u32 = H::ID | T::ID;
+}
+
+impl ByRegion for () { }
+impl<H, T> ByRegion for (H, T) where H: ByRegion, T: ByRegion { }
+
+/// Memory paths consumed by draw commands, including shader storage, image and atomic counter reads.
+pub type Draw = (VertexAttribArray, (ElementArray, (Uniform, (TextureFetch, (ShaderStorage, (ShaderImageAccess, AtomicCounter))))));
+
+thread_local! {
+    /// Barrier bits with writes that were not yet made visible by a barrier.
+    static PENDING: Cell<u32> = const { Cell::new(0) };
+}
+
+/// Order memory transactions issued prior to the barrier relative to those issued after it.
+pub fn memory<B: Barrier>() {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::MemoryBarrier(B::ID);
+        }
+    }
+    clear::<B>();
+}
+
+/// Variant of `memory` that only orders accesses within the framebuffer region being rasterized.
+pub fn memory_by_region<B: ByRegion>() {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::MemoryBarrierByRegion(B::ID);
+        }
+    }
+    clear::<B>();
+}
+
+/// Mark writes through memory paths described by `B` as visible.
+fn clear<B: Barrier>() {
+    PENDING.with(|pending| pending.set(pending.get() & !B::ID));
+}
+
+/// Record incoherent writes that will be consumed through memory paths described by `B`.
+///
+/// Has no effect in release builds.
+pub fn written<B: Barrier>() {
+    if cfg!(debug_assertions) {
+        PENDING.with(|pending| pending.set(pending.get() | B::ID));
+    }
+}
+
+/// Check that memory paths described by `B` have no writes pending a barrier.
+///
+/// In Debug mode it panics if barrier is missing.
+/// In Release it does nothing.
+pub(crate) fn check<B: Barrier>() {
+    if cfg!(debug_assertions) {
+        let missing = PENDING.with(Cell::get) & B::ID;
+        if missing != 0 {
+            panic!("missing memory barrier: pending writes for barrier bits {missing:#x}");
+        }
+    }
+}
+
+/// Tracking only happens in debug builds.
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    fn pending() -> u32 {
+        PENDING.with(Cell::get)
+    }
+
+    #[test]
+    fn written_bits_are_pending_until_cleared() {
+        written::<(ShaderStorage, TextureFetch)>();
+        assert_eq!(pending(), ShaderStorage::ID | TextureFetch::ID);
+        check::<(Command, PixelBuffer)>();
+
+        clear::<ShaderStorage>();
+        assert_eq!(pending(), TextureFetch::ID);
+        clear::<All>();
+        assert_eq!(pending(), 0);
+        check::<Draw>();
+    }
+
+    #[test]
+    #[should_panic(expected = "missing memory barrier: pending writes for barrier bits 0x2000")]
+    fn check_of_pending_write_panics() {
+        written::<ShaderStorage>();
+        check::<Draw>();
+    }
+}
@@ -169,6 +169,25 @@ where
         };
     }
 
+    /// Allocate storage for `len` elements, filled with zeros so that mapping it never exposes uninitialized memory.
+    pub fn allocate<U>(&mut self, len: usize)
+    where
+        U: Usage,
+    {
+        if self.state.length > 0 && self.state.length != len {
+            panic!("realocating buffers with mutable storage is not supported");
+        }
+        let binder = self.bind();
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BufferData(T::ID, (std::mem::size_of::<GL>() * len) as _, std::ptr::null(), U::ID);
+                glb::ClearBufferData(T::ID, glb::R8, glb::RED, glb::UNSIGNED_BYTE, std::ptr::null());
+            }
+        }
+        self.state.length = len;
+    }
+
     pub fn len(&self) -> usize {
         self.state.length
     }
@@ -0,0 +1,121 @@
+//! Framebuffer attachment points and images that can be attached to them.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use gl::texture;
+use texture::image;
+
+/// Attachment point of the framebuffer.
+pub trait Point {
+    const ID: u32;
+}
+
+/// Color attachment at given index. Fragment shader output with `location = N` is written into it.
+pub enum Color<const N: usize> { }
+
+pub enum Depth { }
+
+pub enum Stencil { }
+
+pub enum DepthStencil { }
+
+impl<const N: usize> Point for Color<N> {
+    const ID: u32 = glb::COLOR_ATTACHMENT0 + N as u32;
+}
+
+gl::impl_token! { Depth        as Point => DEPTH_ATTACHMENT         }
+gl::impl_token! { Stencil      as Point => STENCIL_ATTACHMENT       }
+gl::impl_token! { DepthStencil as Point => DEPTH_STENCIL_ATTACHMENT }
+
+/// Image formats that can be attached to given attachment point.
+pub trait ForPoint<P: Point>: image::marker::BaseFormat { }
+
+impl<const N: usize> ForPoint<Color<N>> for image::format::RED { }
+impl<const N: usize> ForPoint<Color<N>> for image::format::RG { }
+impl<const N: usize> ForPoint<Color<N>> for image::format::RGB { }
+impl<const N: usize> ForPoint<Color<N>> for image::format::RGBA { }
+
+impl ForPoint<Depth> for image::format::DEPTH_COMPONENT { }
+impl ForPoint<Depth> for image::format::DEPTH_STENCIL { }
+impl ForPoint<Stencil> for image::format::STENCIL_INDEX { }
+impl ForPoint<Stencil> for image::format::DEPTH_STENCIL { }
+impl ForPoint<DepthStencil> for image::format::DEPTH_STENCIL { }
+
+/// Image that framebuffer attachment point can refer to.
+pub trait Image {
+    type InternalFormat: image::marker::Format;
+}
+
+/// Object whose image can be attached to a framebuffer.
+pub trait Attachable: Image {
+    /// Attach level 0 of the image to the given attachment point of the framebuffer.
+    fn attach(&self, framebuffer: u32, point: u32);
+
+    /// Width and height of the attached image.
+    fn extent(&self) -> [usize; 2];
+}
+
+impl<Target, Kind, InternalFormat, const MIPMAPS: bool> Image for texture::Texture<Target, Kind, InternalFormat, MIPMAPS>
+where
+    Target: texture::Target,
+    Kind: texture::storage::marker::Storage<Target = Target>,
+    InternalFormat: image::marker::Format,
+{
+    type InternalFormat = InternalFormat;
+}
+
+impl<Target, Kind, InternalFormat, const MIPMAPS: bool> Attachable for texture::Texture<Target, Kind, InternalFormat, MIPMAPS>
+where
+    Target: texture::Target,
+    Kind: texture::storage::marker::Storage<Target = Target>,
+    InternalFormat: image::marker::Format,
+{
+    fn attach(&self, framebuffer: u32, point: u32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::NamedFramebufferTexture(framebuffer, point, self.name(), 0);
+            }
+        }
+    }
+
+    fn extent(&self) -> [usize; 2] {
+        let dimensions = self.dimensions().as_ref();
+        [dimensions[0], dimensions.get(1).copied().unwrap_or(1)]
+    }
+}
+
+/// Color image of the default framebuffer provided by the window system.
+pub enum Window { }
+
+impl Image for Window {
+    type InternalFormat = image::Format<image::format::RGBA, u8>;
+}
+
+/// Image attached to the framebuffer at attachment point `P`.
+#[derive(dm::AsRef, dm::AsMut)]
+pub struct Attachment<I, P>
+where
+    I: Image,
+    P: Point,
+{
+    #[as_ref]
+    #[as_mut]
+    image: I,
+    point: PhantomData<P>,
+}
+
+impl<I, P> Attachment<I, P>
+where
+    I: Image,
+    P: Point,
+{
+    pub(super) fn new(image: I) -> Self {
+        Self { image, point: PhantomData }
+    }
+
+    pub fn into_inner(self) -> I {
+        self.image
+    }
+}
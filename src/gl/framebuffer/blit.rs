@@ -0,0 +1,276 @@
+//! Copies of pixel rectangles between framebuffers.
+//!
+//! Mask selects which buffers are copied and is validated against attachments of both framebuffers:
+//! - color is read from color attachment 0 of the source and written into all color attachments of the destination,
+//!   which must all store the same kind of values (float, signed or unsigned integer),
+//! - depth and stencil attachments must have identical internal formats,
+//! - depth, stencil and integer color buffers can only be copied with `Nearest` filtering.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use gl::framebuffer::{self, valid, Attachable, Image, Target};
+use gl::object::ObjectBase;
+use gl::state::Rect;
+use gl::texture;
+use crate::hlist::counters::Index;
+
+/// Type level bitfield of buffers to copy.
+pub trait Mask {
+    const ID: u32;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Color;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Depth;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Stencil;
+
+gl::impl_token! { Color   as Mask => COLOR_BUFFER_BIT   }
+gl::impl_token! { Depth   as Mask => DEPTH_BUFFER_BIT   }
+gl::impl_token! { Stencil as Mask => STENCIL_BUFFER_BIT }
+
+impl<H, T> Mask for (H, T)
+where
+    H: Mask,
+    T: Mask,
+{
+    const ID: u32 = H::ID | T::ID;
+}
+
+/// Interpolation applied when source and destination rectangles differ in size.
+pub trait Filter {
+    const ID: u32;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Nearest;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Linear;
+
+gl::impl_token! { Nearest as Filter => NEAREST }
+gl::impl_token! { Linear  as Filter => LINEAR  }
+
+/// Masks that can be copied using filter `F`.
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be blitted with `{F}` filter", note = "depth and stencil blits require `Nearest` filter")]
+#[hi::marker]
+pub trait ForFilter<F: Filter>: Mask { }
+
+hi::denmark! { Color as ForFilter<Nearest>, ForFilter<Linear> }
+hi::denmark! { Depth as ForFilter<Nearest> }
+hi::denmark! { Stencil as ForFilter<Nearest> }
+
+impl<H, T, F> ForFilter<F> for (H, T)
+where
+    H: ForFilter<F>,
+    T: ForFilter<F>,
+    F: Filter,
+{ }
+
+/// Filters that do not interpolate between pixels.
+#[diagnostic::on_unimplemented(message = "integer color buffers cannot be blitted with `{Self}` filter", note = "integer color blits require `Nearest` filter")]
+#[hi::marker]
+pub trait Exact: Filter { }
+
+hi::denmark! { Nearest as Exact }
+
+/// Kinds of color values that can be copied using filter `F`.
+pub trait KindForFilter<F: Filter>: gl::types::Kind { }
+
+impl<F: Filter> KindForFilter<F> for gl::types::Float { }
+impl<F: Exact> KindForFilter<F> for gl::types::Integer { }
+
+/// Masks whose buffers can be copied with filter `F` from framebuffer with `Src` attachments to one with `Dst` attachments.
+///
+/// `Indices` are type level indices of looked up attachments and should be inferred.
+pub trait Compatible<Src, Dst, F, Indices>: Mask { }
+
+impl<Src, Dst, F, I> Compatible<Src, Dst, F, I> for Color
+where
+    Src: valid::ColorAt<0, I>,
+    Dst: valid::ColorsOutput<<<Src::Image as Image>::InternalFormat as texture::image::marker::Format>::Output>,
+    <<<Src::Image as Image>::InternalFormat as texture::image::marker::Format>::Output as glsl::sampler::Output>::Kind: KindForFilter<F>,
+    F: Filter,
+    I: Index,
+{ }
+
+impl<Src, Dst, F, I, J> Compatible<Src, Dst, F, (I, J)> for Depth
+where
+    Src: valid::DepthAt<I>,
+    Dst: valid::DepthAt<J>,
+    Dst::Image: Image<InternalFormat = <Src::Image as Image>::InternalFormat>,
+    I: Index,
+    J: Index,
+{ }
+
+impl<Src, Dst, F, I, J> Compatible<Src, Dst, F, (I, J)> for Stencil
+where
+    Src: valid::StencilAt<I>,
+    Dst: valid::StencilAt<J>,
+    Dst::Image: Image<InternalFormat = <Src::Image as Image>::InternalFormat>,
+    I: Index,
+    J: Index,
+{ }
+
+impl<Src, Dst, F, H, T, HI, TI> Compatible<Src, Dst, F, (HI, TI)> for (H, T)
+where
+    H: Compatible<Src, Dst, F, HI>,
+    T: Compatible<Src, Dst, F, TI>,
+{ }
+
+fn blit_names(src: u32, src_rect: Rect, dst: u32, dst_rect: Rect, mask: u32, filter: u32) {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::BlitNamedFramebuffer(
+                src,
+                dst,
+                src_rect.x,
+                src_rect.y,
+                src_rect.x + src_rect.width as i32,
+                src_rect.y + src_rect.height as i32,
+                dst_rect.x,
+                dst_rect.y,
+                dst_rect.x + dst_rect.width as i32,
+                dst_rect.y + dst_rect.height as i32,
+                mask,
+                filter,
+            );
+        }
+    }
+}
+
+/// Copy rectangle of pixels from `src` framebuffer into rectangle of `dst` framebuffer.
+pub fn blit<Src, Dst, M, F, Indices>(src: &Src, src_rect: Rect, dst: &mut Dst, dst_rect: Rect, _: M, _: F)
+where
+    Src: Target,
+    Dst: Target,
+    M: Compatible<Src::Attachments, Dst::Attachments, F, Indices> + ForFilter<F>,
+    F: Filter,
+{
+    blit_names(src.name(), src_rect, dst.name(), dst_rect, M::ID, F::ID);
+}
+
+/// Number of samples of framebuffer `name`, 0 if it is single sampled.
+fn samples(name: u32) -> usize {
+    let mut samples = 0;
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::GetNamedFramebufferParameteriv(name, glb::SAMPLES, &mut samples);
+        }
+    }
+    samples as _
+}
+
+/// Width and height of images attached to color attachments of framebuffer object `name`.
+fn color_extents(name: u32) -> Vec<[usize; 2]> {
+    let mut max_attachments = 0;
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::GetIntegerv(glb::MAX_COLOR_ATTACHMENTS, &mut max_attachments);
+        }
+    }
+    let parameter = |attachment: u32, parameter: u32| {
+        let mut value = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetNamedFramebufferAttachmentParameteriv(name, attachment, parameter, &mut value);
+            }
+        }
+        value
+    };
+    (0..max_attachments as u32)
+        .map(|index| glb::COLOR_ATTACHMENT0 + index)
+        .filter_map(|attachment| {
+            let [mut width, mut height] = [0; 2];
+            match parameter(attachment, glb::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE) as u32 {
+                glb::TEXTURE => {
+                    let texture = parameter(attachment, glb::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME) as u32;
+                    let level = parameter(attachment, glb::FRAMEBUFFER_ATTACHMENT_TEXTURE_LEVEL);
+                    gl::call! {
+                        [panic]
+                        unsafe {
+                            glb::GetTextureLevelParameteriv(texture, level, glb::TEXTURE_WIDTH, &mut width);
+                            glb::GetTextureLevelParameteriv(texture, level, glb::TEXTURE_HEIGHT, &mut height);
+                        }
+                    }
+                }
+                glb::RENDERBUFFER => {
+                    let renderbuffer = parameter(attachment, glb::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME) as u32;
+                    gl::call! {
+                        [panic]
+                        unsafe {
+                            glb::GetNamedRenderbufferParameteriv(renderbuffer, glb::RENDERBUFFER_WIDTH, &mut width);
+                            glb::GetNamedRenderbufferParameteriv(renderbuffer, glb::RENDERBUFFER_HEIGHT, &mut height);
+                        }
+                    }
+                }
+                _ => return None,
+            }
+            Some([width as _, height as _])
+        })
+        .collect()
+}
+
+/// Check that `dst` can receive resolve of multisample image of `extent`.
+///
+/// Extent of the default framebuffer is owned by the window system and is not checked.
+fn check_resolve(extent: [usize; 2], dst: u32) {
+    let samples = samples(dst);
+    if samples != 0 {
+        panic!("resolve destination has {samples} samples instead of being single sampled");
+    }
+    if dst == 0 {
+        return;
+    }
+    if let Some([width, height]) = color_extents(dst).into_iter().find(|&dst_extent| dst_extent != extent) {
+        let [src_width, src_height] = extent;
+        panic!("resolve source of {src_width}x{src_height} does not match {width}x{height} destination color attachment");
+    }
+}
+
+/// Resolve color attachment 0 of multisample framebuffer into single sampled `dst`.
+///
+/// Entire source image is copied into rectangle of the same size at the origin of `dst`.
+/// Color attachments of `dst` must have the same size as the source, otherwise it panics.
+pub fn resolve<SrcAtts, Dst, I>(src: &framebuffer::Framebuffer<SrcAtts>, dst: &mut Dst)
+where
+    SrcAtts: valid::Attachments + valid::ColorAt<0, I>,
+    SrcAtts::Image: Attachable,
+    Dst: Target,
+    Dst::Attachments: valid::ColorsOutput<<<SrcAtts::Image as Image>::InternalFormat as texture::image::marker::Format>::Output>,
+    I: Index,
+{
+    let [width, height] = src.attachments.image().extent();
+    check_resolve([width, height], dst.name());
+    let rect = Rect::new(0, 0, width, height);
+    blit_names(Target::name(src), rect, dst.name(), rect, Color::ID, Nearest::ID);
+}
+
+/// Resolve multisample texture into single sampled `dst`.
+///
+/// Color attachments of `dst` must have the same size as the texture, otherwise it panics.
+pub fn resolve_texture<Kind, InternalFormat, Dst>(src: &texture::Texture<texture::target::D2MultiSample, Kind, InternalFormat>, dst: &mut Dst)
+where
+    Kind: texture::storage::marker::Storage<Target = texture::target::D2MultiSample>,
+    InternalFormat: texture::image::marker::Format,
+    InternalFormat::BaseFormat: framebuffer::attachment::ForPoint<framebuffer::attachment::Color<0>>,
+    Dst: Target,
+    Dst::Attachments: valid::ColorsOutput<InternalFormat::Output>,
+{
+    let read = ObjectBase::<framebuffer::FramebufferObject>::default();
+    src.attach(read.name(), glb::COLOR_ATTACHMENT0);
+
+    let [width, height] = src.extent();
+    check_resolve([width, height], dst.name());
+    let rect = Rect::new(0, 0, width, height);
+    blit_names(read.name(), rect, dst.name(), rect, Color::ID, Nearest::ID);
+}
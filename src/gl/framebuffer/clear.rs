@@ -0,0 +1,122 @@
+//! Clears of framebuffer attachments with values typed by their internal formats.
+//!
+//! Color attachments are cleared with `[f32; 4]`, `[i32; 4]` or `[u32; 4]` depending on kind of values
+//! their internal format stores, so that integer attachment can not be cleared with float data.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use crate::hlist::counters::Index;
+use gl::framebuffer::{valid, Framebuffer, Image};
+use gl::texture::image::marker::Format;
+
+/// Sampler outputs of internal formats paired with matching clear entry point.
+pub trait Clear: glsl::sampler::Output {
+    type Value;
+
+    fn clear(framebuffer: u32, buffer: u32, draw_buffer: i32, value: &Self::Value);
+}
+
+impl Clear for f32 {
+    type Value = [f32; 4];
+
+    fn clear(framebuffer: u32, buffer: u32, draw_buffer: i32, value: &Self::Value) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferfv(framebuffer, buffer, draw_buffer, value.as_ptr());
+            }
+        }
+    }
+}
+
+impl Clear for i32 {
+    type Value = [i32; 4];
+
+    fn clear(framebuffer: u32, buffer: u32, draw_buffer: i32, value: &Self::Value) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferiv(framebuffer, buffer, draw_buffer, value.as_ptr());
+            }
+        }
+    }
+}
+
+impl Clear for u32 {
+    type Value = [u32; 4];
+
+    fn clear(framebuffer: u32, buffer: u32, draw_buffer: i32, value: &Self::Value) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferuiv(framebuffer, buffer, draw_buffer, value.as_ptr());
+            }
+        }
+    }
+}
+
+/// Clear value of the image attached at color attachment `N`.
+pub type ColorValue<Atts, const N: usize, I> =
+    <<<<Atts as valid::ColorAt<N, I>>::Image as Image>::InternalFormat as Format>::Output as Clear>::Value;
+
+impl<Atts> Framebuffer<Atts>
+where
+    Atts: valid::Attachments,
+{
+    /// Clear color attachment `N` to `value`.
+    ///
+    /// `I` is type level index of the attachment and should be inferred, e.g. `clear_color::<0, _>(value)`.
+    pub fn clear_color<const N: usize, I>(&mut self, value: ColorValue<Atts, N, I>)
+    where
+        Atts: valid::ColorAt<N, I>,
+        <<Atts::Image as Image>::InternalFormat as Format>::Output: Clear,
+        I: Index,
+    {
+        <<<Atts::Image as Image>::InternalFormat as Format>::Output as Clear>::clear(self.name(), glb::COLOR, N as _, &value);
+    }
+
+    /// Clear depth attachment to `depth`.
+    pub fn clear_depth<I>(&mut self, depth: f32)
+    where
+        Atts: valid::DepthAt<I>,
+        I: Index,
+    {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferfv(self.name(), glb::DEPTH, 0, &depth);
+            }
+        }
+    }
+
+    /// Clear stencil attachment to `stencil`.
+    pub fn clear_stencil<I>(&mut self, stencil: i32)
+    where
+        Atts: valid::StencilAt<I>,
+        I: Index,
+    {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferiv(self.name(), glb::STENCIL, 0, &stencil);
+            }
+        }
+    }
+
+    /// Clear depth and stencil attachments at once.
+    pub fn clear_depth_stencil<I, J>(&mut self, depth: f32, stencil: i32)
+    where
+        Atts: valid::DepthAt<I> + valid::StencilAt<J>,
+        I: Index,
+        J: Index,
+    {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ClearNamedFramebufferfi(self.name(), glb::DEPTH_STENCIL, 0, depth, stencil);
+            }
+        }
+    }
+}
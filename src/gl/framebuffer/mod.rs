@@ -0,0 +1,295 @@
+//! OpenGL Framebuffer Object.
+//!
+//! Framebuffer owns images attached to it. Attachments are tracked in its type so that programs can be checked
+//! to only write their fragment outputs into color attachments of compatible internal format.
+
+pub mod attachment;
+pub mod blit;
+pub mod clear;
+pub mod valid;
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use crate::hlist;
+use gl::object::*;
+use gl::texture;
+
+pub use attachment::{Attachable, Attachment, Image};
+pub use blit::{blit, resolve, resolve_texture};
+
+#[hi::mark(PartialObject, Object)]
+pub enum FramebufferObject { }
+
+unsafe impl Allocator for FramebufferObject {
+    fn allocate(names: &mut [u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::CreateFramebuffers(names.len() as _, names.as_mut_ptr());
+            }
+        }
+    }
+
+    fn free(names: &[u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::DeleteFramebuffers(names.len() as _, names.as_ptr());
+            }
+        }
+    }
+}
+
+impl Binder for FramebufferObject {
+    fn bind(name: u32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindFramebuffer(glb::FRAMEBUFFER, name);
+            }
+        }
+    }
+}
+
+/// Reasons for framebuffer incompleteness reported by `glCheckNamedFramebufferStatus`.
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Incomplete {
+    #[error("default framebuffer does not exist")]
+    Undefined,
+    #[error("attachment is not framebuffer complete")]
+    Attachment,
+    #[error("framebuffer has no images attached")]
+    MissingAttachment,
+    #[error("draw buffer names attachment point without image")]
+    DrawBuffer,
+    #[error("read buffer names attachment point without image")]
+    ReadBuffer,
+    #[error("combination of internal formats of attached images is not supported")]
+    Unsupported,
+    #[error("attached images have different sample counts or fixed sample location settings")]
+    Multisample,
+    #[error("attachments are not all layered or layered attachments have different targets")]
+    LayerTargets,
+    /// Status not listed above, including `0` returned when the check itself generates an error.
+    #[error("unknown framebuffer status {0:#x}")]
+    Unknown(u32),
+}
+
+impl Incomplete {
+    /// Map framebuffer status to error, `None` if framebuffer is complete.
+    pub fn new(status: u32) -> Option<Self> {
+        match status {
+            glb::FRAMEBUFFER_COMPLETE => None,
+            glb::FRAMEBUFFER_UNDEFINED => Some(Self::Undefined),
+            glb::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => Some(Self::Attachment),
+            glb::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => Some(Self::MissingAttachment),
+            glb::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => Some(Self::DrawBuffer),
+            glb::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => Some(Self::ReadBuffer),
+            glb::FRAMEBUFFER_UNSUPPORTED => Some(Self::Unsupported),
+            glb::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => Some(Self::Multisample),
+            glb::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => Some(Self::LayerTargets),
+            status => Some(Self::Unknown(status)),
+        }
+    }
+}
+
+#[derive(dm::Deref)]
+pub struct Framebuffer<Attachments>
+where
+    Attachments: valid::Attachments,
+{
+    #[deref]
+    object: ObjectBase<FramebufferObject>,
+    attachments: Attachments,
+}
+
+/// Framebuffers that can act as a source or destination of framebuffer operations.
+pub trait Target {
+    type Attachments;
+
+    fn name(&self) -> u32;
+}
+
+impl<Atts> Target for Framebuffer<Atts>
+where
+    Atts: valid::Attachments,
+{
+    type Attachments = Atts;
+
+    fn name(&self) -> u32 {
+        self.object.name()
+    }
+}
+
+/// Attachments of the default framebuffer.
+pub type DefaultAttachments = ((), Attachment<attachment::Window, attachment::Color<0>>);
+
+/// Framebuffer provided by the window system.
+pub struct DefaultFramebuffer;
+
+impl Target for DefaultFramebuffer {
+    type Attachments = DefaultAttachments;
+
+    fn name(&self) -> u32 {
+        0
+    }
+}
+
+impl Framebuffer<()> {
+    pub fn create() -> Self {
+        Self {
+            object: Default::default(),
+            attachments: (),
+        }
+    }
+}
+
+impl<Atts> Framebuffer<Atts>
+where
+    Atts: valid::Attachments,
+{
+    fn attach<I, P>(self, image: I) -> Framebuffer<(Atts, Attachment<I, P>)>
+    where
+        I: Attachable,
+        P: attachment::Point + valid::ColorIndex,
+        <I::InternalFormat as gl::texture::image::marker::Format>::BaseFormat: attachment::ForPoint<P>,
+    {
+        image.attach(self.name(), P::ID);
+        let Self { object, attachments } = self;
+        let framebuffer = Framebuffer { object, attachments: (attachments, Attachment::new(image)) };
+        framebuffer.draw_buffers();
+        framebuffer
+    }
+
+    /// Route fragment output at `location = N` into color attachment `N`.
+    fn draw_buffers(&self) {
+        let mut indices = Vec::new();
+        Atts::color_indices(&mut indices);
+        let mut buffers = vec![glb::NONE; indices.iter().max().map_or(0, |max| max + 1)];
+        for index in indices {
+            buffers[index] = glb::COLOR_ATTACHMENT0 + index as u32;
+        }
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::NamedFramebufferDrawBuffers(self.name(), buffers.len() as _, buffers.as_ptr());
+            }
+        }
+    }
+
+    /// Attach image as color attachment `N`.
+    pub fn color<I, const N: usize>(self, image: I) -> Framebuffer<(Atts, Attachment<I, attachment::Color<N>>)>
+    where
+        I: Attachable,
+        <I::InternalFormat as gl::texture::image::marker::Format>::BaseFormat: attachment::ForPoint<attachment::Color<N>>,
+    {
+        self.attach(image)
+    }
+
+    pub fn depth<I>(self, image: I) -> Framebuffer<(Atts, Attachment<I, attachment::Depth>)>
+    where
+        I: Attachable,
+        <I::InternalFormat as gl::texture::image::marker::Format>::BaseFormat: attachment::ForPoint<attachment::Depth>,
+    {
+        self.attach(image)
+    }
+
+    pub fn stencil<I>(self, image: I) -> Framebuffer<(Atts, Attachment<I, attachment::Stencil>)>
+    where
+        I: Attachable,
+        <I::InternalFormat as gl::texture::image::marker::Format>::BaseFormat: attachment::ForPoint<attachment::Stencil>,
+    {
+        self.attach(image)
+    }
+
+    pub fn depth_stencil<I>(self, image: I) -> Framebuffer<(Atts, Attachment<I, attachment::DepthStencil>)>
+    where
+        I: Attachable,
+        <I::InternalFormat as gl::texture::image::marker::Format>::BaseFormat: attachment::ForPoint<attachment::DepthStencil>,
+    {
+        self.attach(image)
+    }
+
+    /// Check framebuffer completeness.
+    pub fn status(&self) -> Result<(), Incomplete> {
+        let status;
+        gl::call! {
+            [panic]
+            unsafe {
+                status = glb::CheckNamedFramebufferStatus(self.name(), glb::FRAMEBUFFER);
+            }
+        }
+        Incomplete::new(status).map_or(Ok(()), Err)
+    }
+
+    /// Check framebuffer completeness, returning it back if complete.
+    pub fn complete(self) -> Result<Self, Incomplete> {
+        self.status().map(|_| self)
+    }
+
+    pub fn attachment_ref<I, P, IDX>(&self) -> &I
+    where
+        I: Image,
+        P: attachment::Point + 'static,
+        IDX: hlist::counters::Index,
+        Atts: hlist::lhlist::Find<Attachment<I, P>, IDX>,
+    {
+        self.attachments.get().as_ref()
+    }
+
+    pub fn attachment_mut<I, P, IDX>(&mut self) -> &mut I
+    where
+        I: Image,
+        P: attachment::Point + 'static,
+        IDX: hlist::counters::Index,
+        Atts: hlist::lhlist::Find<Attachment<I, P>, IDX>,
+    {
+        self.attachments.get_mut().as_mut()
+    }
+
+    /// Read back rectangle of pixels from color attachment 0.
+    ///
+    /// Rows are returned bottom to top, as stored by OpenGL.
+    pub fn read_pixels<Channels, Pixel, I>(&self, rect: gl::state::Rect) -> Vec<Pixel>
+    where
+        Atts: valid::ColorAt<0, I>,
+        Atts::Image: Attachable,
+        I: hlist::counters::Index,
+        Channels: texture::pixel::channels::Channels
+            + texture::pixel::valid::ForImageBaseFormat<<<Atts::Image as Image>::InternalFormat as texture::image::marker::Format>::BaseFormat>,
+        Pixel: texture::pixel::Pixel<
+            Components = Channels::Components,
+            Type = <<Atts::Image as Image>::InternalFormat as texture::image::marker::Format>::ComponentType,
+        > + texture::pixel::valid::ForChannels<Channels>,
+        (Channels, <<<Atts::Image as Image>::InternalFormat as texture::image::marker::Format>::Output as glsl::sampler::Output>::Kind): texture::pixel::FormatToken,
+    {
+        let [width, height] = self.attachments.image().extent();
+        if rect.x < 0 || rect.y < 0 || rect.x as usize + rect.width > width || rect.y as usize + rect.height > height {
+            panic!("read rectangle {rect:?} extends out of bounds of {width}x{height} attachment");
+        }
+
+        let length = rect.width * rect.height;
+        let mut pixels = texture::pixel::zeroed::<Pixel>(length);
+        let _bind = self.bind();
+        texture::pixel::pack_rows::<Pixel>(rect.width);
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::NamedFramebufferReadBuffer(self.name(), glb::COLOR_ATTACHMENT0);
+                glb::ReadnPixels(
+                    rect.x,
+                    rect.y,
+                    rect.width as _,
+                    rect.height as _,
+                    <(Channels, <<<Atts::Image as Image>::InternalFormat as texture::image::marker::Format>::Output as glsl::sampler::Output>::Kind) as texture::pixel::FormatToken>::ID,
+                    Pixel::type_token(),
+                    (length * std::mem::size_of::<Pixel>()) as _,
+                    pixels.as_mut_ptr() as *mut _,
+                );
+            }
+        }
+        pixels
+    }
+}
@@ -0,0 +1,213 @@
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use crate::hlist::counters::{Index, Successor, Zero};
+use gl::framebuffer::attachment::{self, Attachment, Image, Point};
+use gl::texture::image::marker::Format;
+use glsl::variable::OutVariable;
+
+/// Lists of framebuffer attachments.
+pub trait Attachments: crate::hlist::lhlist::Base {
+    /// Record indices of color attachments in list order.
+    fn color_indices(indices: &mut Vec<usize>);
+}
+
+impl Attachments for () {
+    fn color_indices(_: &mut Vec<usize>) { }
+}
+
+impl<H, I, P> Attachments for (H, Attachment<I, P>)
+where
+    H: Attachments,
+    I: Image,
+    P: Point + ColorIndex,
+{
+    fn color_indices(indices: &mut Vec<usize>) {
+        H::color_indices(indices);
+        if let Some(index) = P::INDEX {
+            indices.push(index);
+        }
+    }
+}
+
+/// Index of color attachment point, if point is one.
+pub trait ColorIndex {
+    const INDEX: Option<usize>;
+}
+
+impl<const N: usize> ColorIndex for attachment::Color<N> {
+    const INDEX: Option<usize> = Some(N);
+}
+
+impl ColorIndex for attachment::Depth {
+    const INDEX: Option<usize> = None;
+}
+
+impl ColorIndex for attachment::Stencil {
+    const INDEX: Option<usize> = None;
+}
+
+impl ColorIndex for attachment::DepthStencil {
+    const INDEX: Option<usize> = None;
+}
+
+/// Lookup of image attached at color attachment `N`.
+#[diagnostic::on_unimplemented(
+    message = "framebuffer has no color attachment {N}",
+    note = "fragment output with `location = {N}` requires color attachment {N}"
+)]
+pub trait ColorAt<const N: usize, I: Index> {
+    type Image: Image;
+
+    fn image(&self) -> &Self::Image;
+}
+
+impl<H, Img, const N: usize> ColorAt<N, Zero> for (H, Attachment<Img, attachment::Color<N>>)
+where
+    Img: Image,
+{
+    type Image = Img;
+
+    fn image(&self) -> &Self::Image {
+        self.1.as_ref()
+    }
+}
+
+impl<H, T, const N: usize, I> ColorAt<N, Successor<I>> for (H, T)
+where
+    H: ColorAt<N, I>,
+    I: Index,
+{
+    type Image = H::Image;
+
+    fn image(&self) -> &Self::Image {
+        self.0.image()
+    }
+}
+
+/// Lookup of image attached at depth or depth-stencil attachment.
+#[diagnostic::on_unimplemented(message = "framebuffer has no depth attachment")]
+pub trait DepthAt<I: Index> {
+    type Image: Image;
+}
+
+impl<H, Img> DepthAt<Zero> for (H, Attachment<Img, attachment::Depth>)
+where
+    Img: Image,
+{
+    type Image = Img;
+}
+
+impl<H, Img> DepthAt<Zero> for (H, Attachment<Img, attachment::DepthStencil>)
+where
+    Img: Image,
+{
+    type Image = Img;
+}
+
+impl<H, T, I> DepthAt<Successor<I>> for (H, T)
+where
+    H: DepthAt<I>,
+    I: Index,
+{
+    type Image = H::Image;
+}
+
+/// Lookup of image attached at stencil or depth-stencil attachment.
+#[diagnostic::on_unimplemented(message = "framebuffer has no stencil attachment")]
+pub trait StencilAt<I: Index> {
+    type Image: Image;
+}
+
+impl<H, Img> StencilAt<Zero> for (H, Attachment<Img, attachment::Stencil>)
+where
+    Img: Image,
+{
+    type Image = Img;
+}
+
+impl<H, Img> StencilAt<Zero> for (H, Attachment<Img, attachment::DepthStencil>)
+where
+    Img: Image,
+{
+    type Image = Img;
+}
+
+impl<H, T, I> StencilAt<Successor<I>> for (H, T)
+where
+    H: StencilAt<I>,
+    I: Index,
+{
+    type Image = H::Image;
+}
+
+/// Attachment lists whose every color attachment has internal format producing output `O`.
+#[diagnostic::on_unimplemented(message = "color attachments of `{Self}` do not all store `{O}` values")]
+pub trait ColorsOutput<O: glsl::sampler::Output> { }
+
+impl<O> ColorsOutput<O> for () where O: glsl::sampler::Output { }
+
+impl<H, Img, O, const N: usize> ColorsOutput<O> for (H, Attachment<Img, attachment::Color<N>>)
+where
+    H: ColorsOutput<O>,
+    Img: Image,
+    Img::InternalFormat: Format<Output = O>,
+    O: glsl::sampler::Output,
+{ }
+
+impl<H, Img, O> ColorsOutput<O> for (H, Attachment<Img, attachment::Depth>)
+where
+    H: ColorsOutput<O>,
+    Img: Image,
+    O: glsl::sampler::Output,
+{ }
+
+impl<H, Img, O> ColorsOutput<O> for (H, Attachment<Img, attachment::Stencil>)
+where
+    H: ColorsOutput<O>,
+    Img: Image,
+    O: glsl::sampler::Output,
+{ }
+
+impl<H, Img, O> ColorsOutput<O> for (H, Attachment<Img, attachment::DepthStencil>)
+where
+    H: ColorsOutput<O>,
+    Img: Image,
+    O: glsl::sampler::Output,
+{ }
+
+/// Glsl fragment output types that can be written into image which produces sampler output `O`.
+#[hi::marker]
+pub trait ForOutput<O: glsl::sampler::Output> { }
+
+hi::denmark! { f32 as ForOutput<f32> }
+hi::denmark! { glsl::Vec2 as ForOutput<f32> }
+hi::denmark! { glsl::Vec3 as ForOutput<f32> }
+hi::denmark! { glsl::Vec4 as ForOutput<f32> }
+hi::denmark! { i32 as ForOutput<i32> }
+hi::denmark! { glsl::IVec2 as ForOutput<i32> }
+hi::denmark! { glsl::IVec3 as ForOutput<i32> }
+hi::denmark! { glsl::IVec4 as ForOutput<i32> }
+hi::denmark! { u32 as ForOutput<u32> }
+hi::denmark! { glsl::UVec2 as ForOutput<u32> }
+hi::denmark! { glsl::UVec3 as ForOutput<u32> }
+hi::denmark! { glsl::UVec4 as ForOutput<u32> }
+
+/// Framebuffer attachments which can receive all fragment shader outputs `Outs`.
+///
+/// `Indices` is a list of type level indices of matching attachments and should be inferred.
+#[diagnostic::on_unimplemented(
+    message = "framebuffer does not provide compatible color attachments for fragment outputs `{Outs}`",
+    note = "every fragment output with `location = N` requires color attachment `N` with matching internal format"
+)]
+pub trait Renders<Outs, Indices> { }
+
+impl<Atts> Renders<(), ()> for Atts { }
+
+impl<Atts, H, T, const LOCATION: usize, HI, I> Renders<(H, OutVariable<T, LOCATION>), (HI, I)> for Atts
+where
+    Atts: Renders<H, HI> + ColorAt<LOCATION, I>,
+    I: Index,
+    T: glsl::Type + ForOutput<<<Atts::Image as Image>::InternalFormat as Format>::Output>,
+{ }
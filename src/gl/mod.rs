@@ -2,10 +2,14 @@
 
 use crate::prelude::internal::*;
 
+pub mod barrier;
 pub mod buffer;
+pub mod framebuffer;
 pub mod program;
+pub mod renderbuffer;
 pub mod object;
 pub mod shader;
+pub mod state;
 pub mod texture;
 pub mod vertex_array;
 pub mod uniform;
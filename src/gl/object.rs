@@ -63,6 +63,14 @@ impl<O: PartialObject> Drop for ObjectBase<O> {
 }
 
 impl<O: PartialObject> ObjectBase<O> {
+    /// Take ownership of object name allocated by other means than [`Allocator::allocate`].
+    ///
+    /// # Safety
+    /// `name` must be a valid name of object `O` not owned by any other `ObjectBase`.
+    pub(in crate::gl) unsafe fn from_name(name: u32) -> Self {
+        Self { name, object: PhantomData }
+    }
+
     pub fn name(&self) -> u32 {
         self.name
     }
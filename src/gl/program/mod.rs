@@ -17,7 +17,7 @@ pub use builder::Builder;
 use crate::gl;
 use crate::glsl;
 use crate::hlist;
-use crate::hlist::counters::Index;
+use crate::hlist::counters::{Index, Successor, Zero};
 use crate::hlist::indexed;
 use hlist::lhlist::Find;
 use crate::valid;
@@ -195,6 +195,7 @@ impl Program<(), (), (), ()> {
     pub fn run_program(&mut self, n_triangles: usize, vao: &gl::VertexArray<()>) {
         let _vao_bind = vao.bind();
         let _program_bind = self.bind();
+        gl::barrier::check::<gl::barrier::Draw>();
 
         gl::call! {
             [panic]
@@ -379,14 +380,15 @@ where
     }
 
     /// Draw arrays using program that uses external resources. Bindings for these resources need to be provided in order to draw. 
-    pub fn draw_arrays_ext<Attrs, Handles>(&self, vao: &gl::VertexArray<Attrs>, handles: &texture::TextureUnits<Handles>)
+    pub fn draw_arrays_ext<Attrs, Handles, ResIndices>(&self, vao: &gl::VertexArray<Attrs>, handles: &texture::TextureUnits<Handles>)
     where
         Attrs: vertex_array::valid::Attributes + glsl::compatible::hlist::Compatible<Ins>,
-        Handles: ResourceProviders<Res> + texture::Binders,
+        Handles: ResourceProviders<Res, ResIndices> + texture::Binders,
     {
         let _vao_bind = vao.bind();
         let _program_bind = self.bind();
         let _texture_binders = handles.binders();
+        gl::barrier::check::<gl::barrier::Draw>();
 
         gl::call! {
             [panic]
@@ -395,6 +397,22 @@ where
             }
         }
     }
+
+    /// Draw arrays into framebuffer which provides compatible color attachments for all fragment outputs.
+    pub fn draw_arrays_into<Attrs, Handles, ResIndices, Atts, Indices>(
+        &self,
+        framebuffer: &gl::framebuffer::Framebuffer<Atts>,
+        vao: &gl::VertexArray<Attrs>,
+        handles: &texture::TextureUnits<Handles>,
+    )
+    where
+        Attrs: vertex_array::valid::Attributes + glsl::compatible::hlist::Compatible<Ins>,
+        Handles: ResourceProviders<Res, ResIndices> + texture::Binders,
+        Atts: gl::framebuffer::valid::Attachments + gl::framebuffer::valid::Renders<Outs, Indices>,
+    {
+        let _framebuffer_bind = framebuffer.bind();
+        self.draw_arrays_ext(vao, handles);
+    }
 }
 
 /// Resource external to the Program, which program can use like textures, images, atomic counters, buffers etc.
@@ -409,13 +427,19 @@ pub(crate) mod private {
     pub trait Sealed { }
     
     impl Sealed for () { }
-    impl<'texture, TUH, Target, Kind, InternalFormat, const BINDING: usize> private::Sealed for (TUH, &'texture TextureUnit<Target, Kind, InternalFormat, BINDING>)
+    impl<'texture, TUH, Target, Kind, InternalFormat, const BINDING: usize, const MIPMAPS: bool> private::Sealed for (TUH, &'texture TextureUnit<Target, Kind, InternalFormat, BINDING, MIPMAPS>)
     where 
         Target: texture::Target,
         Kind: texture::storage::marker::Kind<Target = Target>,
         InternalFormat: texture::image::marker::Format,
     { }
 
+    impl<IUH, Texture, Access, const BINDING: usize> private::Sealed for (IUH, &texture::ImageUnit<Texture, Access, BINDING>)
+    where
+        Texture: texture::image_unit::ImageTexture,
+        Access: glsl::image::memory::Access<Base = Access>,
+    { }
+
     impl<'a, H, U, T, const LOCATION: usize> Sealed for (H, uniform::Definition<'a, U, T, LOCATION>)
     where
         H: SetDefinitions,
@@ -424,17 +448,75 @@ pub(crate) mod private {
     { }
 }
 
+/// Units that provide all resources declared in `Res`, irrespective of their order.
+///
+/// `Indices` locate provider of each resource within the units and are inferred.
+///
+/// Units lacking provider of a declared resource are rejected:
+///
+/// ```compile_fail
+/// use gpu_bulwark::gl::program::ResourceProviders;
+/// use gpu_bulwark::gl::texture::{image::{format::components::RGBA, Format}, target::D2, Immutable, TextureUnit};
+/// use gpu_bulwark::glsl::variable::SamplerVariable;
+///
+/// fn require<Units: ResourceProviders<Res, Indices>, Res, Indices>() { }
+///
+/// // Texture is bound to unit 0 but the program samples binding 1.
+/// require::<((), &'static TextureUnit<D2, Immutable<D2>, Format<RGBA, u8>, 0>), ((), SamplerVariable<D2, f32, 1>), _>();
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "units `{Self}` do not provide all resources `{Res}` declared by the program",
+)]
 #[hi::marker]
-pub trait ResourceProviders<Res>: private::Sealed { }
+pub trait ResourceProviders<Res, Indices>: private::Sealed { }
 
-impl ResourceProviders<()> for () {}
-impl<'texture, Target, Kind, InternalFormat, const BINDING: usize> ResourceProviders<((), glsl::variable::SamplerVariable<Target, InternalFormat::Output, BINDING>)> for ((), &'texture TextureUnit<Target, Kind, InternalFormat, BINDING>)
+impl<Handles> ResourceProviders<(), ()> for Handles
+where
+    Handles: private::Sealed,
+{ }
+
+impl<Handles, Res, Variable, I, Indices> ResourceProviders<(Res, Variable), (Indices, I)> for Handles
+where
+    Handles: ProvideResource<Variable, I> + ResourceProviders<Res, Indices>,
+    I: Index,
+{ }
+
+/// Unit at index `I` of the units provides resource bound to `Variable`.
+///
+/// Variable is matched on its binding, as well as target and format of the unit. Image units also have to grant
+/// access of image variable, read-write units grant any.
+#[diagnostic::on_unimplemented(
+    message = "no unit provides resource `{Variable}`",
+    label = "missing unit for `{Variable}`",
+    note = "each sampler and image declared with `Resources` needs unit of the same `BINDING`, target and format, images also need unit access granting theirs",
+)]
+pub trait ProvideResource<Variable, I>: private::Sealed
+where
+    I: Index,
+{ }
+
+impl<'texture, H, Target, Kind, InternalFormat, const BINDING: usize, const MIPMAPS: bool> ProvideResource<glsl::variable::SamplerVariable<<InternalFormat::Sampling as texture::image::marker::Sampling>::Target<Target>, InternalFormat::Output, BINDING>, Zero> for (H, &'texture TextureUnit<Target, Kind, InternalFormat, BINDING, MIPMAPS>)
 where
     Target: texture::Target,
     Kind: texture::storage::marker::Kind<Target = Target>,
     InternalFormat: texture::image::marker::Format,
 { }
 
+impl<H, Texture, Access, UnitAccess, const BINDING: usize> ProvideResource<glsl::variable::ImageVariable<Texture::Target, <Texture::InternalFormat as texture::image::marker::Format>::Output, Texture::InternalFormat, Access, BINDING>, Zero> for (H, &texture::ImageUnit<Texture, UnitAccess, BINDING>)
+where
+    Texture: texture::image_unit::ImageTexture,
+    Texture::InternalFormat: glsl::image::FormatQualifier,
+    Access: glsl::image::memory::Access,
+    UnitAccess: glsl::image::memory::Access<Base = UnitAccess> + glsl::image::memory::Grants<Access::Base>,
+{ }
+
+impl<H, T, Variable, I> ProvideResource<Variable, Successor<I>> for (H, T)
+where
+    H: ProvideResource<Variable, I>,
+    (H, T): private::Sealed,
+    I: Index,
+{ }
+
 /// Declarations of 'Resource's that program uses.
 pub struct Resources<Res>(PhantomData<Res>);
 
@@ -450,10 +532,65 @@ impl<Res> Resources<Res> {
         self, 
         _: &'_ glsl::variable::SamplerVariable<Target, Output, BINDING>
     ) -> Resources<(Res, glsl::variable::SamplerVariable<Target, Output, BINDING>)>
+    where
+        Target: glsl::sampler::Target,
+        Output: glsl::sampler::Output,
+    {
+        Resources(PhantomData)
+    }
+
+    /// Add declaration of usage of specified image.
+    pub fn image<Target, Output, Format, Access, const BINDING: usize>(
+        self,
+        _: &'_ glsl::variable::ImageVariable<Target, Output, Format, Access, BINDING>
+    ) -> Resources<(Res, glsl::variable::ImageVariable<Target, Output, Format, Access, BINDING>)>
     where
         Target: texture::Target,
         Output: glsl::sampler::Output,
+        Format: glsl::image::FormatQualifier<Output = Output>,
+        Access: glsl::image::memory::Access,
     {
         Resources(PhantomData)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use glsl::image::memory::{Coherent, ReadOnly, ReadWrite, WriteOnly};
+    use texture::image::{format::{components::RGBA, F}, Format};
+    use texture::{target::D2, Immutable, Texture};
+
+    type Image<Access> = &'static texture::ImageUnit<Texture<D2, Immutable<D2>, Format<RGBA, f32, F>>, Access, 0>;
+    type ImageVariable<Access> = glsl::variable::ImageVariable<D2, f32, Format<RGBA, f32, F>, Access, 0>;
+
+    type Sampled<const BINDING: usize> = &'static TextureUnit<D2, Immutable<D2>, Format<RGBA, f32, F>, BINDING>;
+    type SamplerVariable<const BINDING: usize> = glsl::variable::SamplerVariable<D2, f32, BINDING>;
+
+    fn require_provider<Handles: ProvideResource<Variable, I>, Variable, I: Index>() { }
+
+    fn require_providers<Handles: ResourceProviders<Res, Indices>, Res, Indices>() { }
+
+    #[test]
+    fn resources_are_provided_in_any_order() {
+        type Units = ((((), Sampled<0>), Image<ReadWrite>), Sampled<2>);
+        require_providers::<Units, (), _>();
+        require_providers::<Units, ((((), SamplerVariable<0>), ImageVariable<ReadWrite>), SamplerVariable<2>), _>();
+        require_providers::<Units, ((((), SamplerVariable<2>), ImageVariable<ReadWrite>), SamplerVariable<0>), _>();
+        require_providers::<Units, (((), SamplerVariable<2>), SamplerVariable<0>), _>();
+    }
+
+    #[test]
+    fn read_write_image_unit_provides_images_of_any_access() {
+        require_provider::<((), Image<ReadWrite>), ImageVariable<ReadOnly>, _>();
+        require_provider::<((), Image<ReadWrite>), ImageVariable<WriteOnly>, _>();
+        require_provider::<((), Image<ReadWrite>), ImageVariable<ReadWrite>, _>();
+        require_provider::<((), Image<ReadWrite>), ImageVariable<Coherent<ReadOnly>>, _>();
+    }
+
+    #[test]
+    fn image_unit_provides_images_of_its_access() {
+        require_provider::<((), Image<ReadOnly>), ImageVariable<ReadOnly>, _>();
+        require_provider::<((), Image<WriteOnly>), ImageVariable<Coherent<WriteOnly>>, _>();
+    }
+}
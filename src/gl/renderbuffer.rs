@@ -0,0 +1,117 @@
+//! OpenGL Renderbuffer Object.
+//!
+//! Renderbuffers are images optimized for use as render targets which cannot be sampled from.
+//! Their internal format uses the same machinery as textures so they can be attached to a framebuffer wherever
+//! texture of the same format would be accepted.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use gl::framebuffer;
+use gl::object::*;
+use gl::texture::image;
+
+#[hi::mark(PartialObject, Object)]
+pub enum RenderbufferObject { }
+
+unsafe impl Allocator for RenderbufferObject {
+    fn allocate(names: &mut [u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::CreateRenderbuffers(names.len() as _, names.as_mut_ptr());
+            }
+        }
+    }
+
+    fn free(names: &[u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::DeleteRenderbuffers(names.len() as _, names.as_ptr());
+            }
+        }
+    }
+}
+
+impl Binder for RenderbufferObject {
+    fn bind(name: u32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindRenderbuffer(glb::RENDERBUFFER, name);
+            }
+        }
+    }
+}
+
+/// Renderbuffer with storage of given internal format.
+///
+/// `SAMPLES` of 0 denotes single sampled storage. Compressed formats and sampling variants like `Compared` are rejected.
+#[derive(dm::Deref)]
+pub struct Renderbuffer<InternalFormat, const SAMPLES: usize = 0>
+where
+    InternalFormat: image::marker::Storable + image::marker::Uncompressed,
+{
+    #[deref]
+    object: ObjectBase<RenderbufferObject>,
+    format: PhantomData<InternalFormat>,
+    dimensions: [usize; 2],
+}
+
+impl<InternalFormat, const SAMPLES: usize> Renderbuffer<InternalFormat, SAMPLES>
+where
+    InternalFormat: image::marker::Storable + image::marker::Uncompressed,
+{
+    /// Create renderbuffer and allocate its storage.
+    ///
+    /// Fails if `SAMPLES` exceeds maximal sample count supported for the internal format.
+    pub fn create(width: usize, height: usize) -> gl::Result<Self> {
+        let object = ObjectBase::<RenderbufferObject>::default();
+        gl::call! {
+            [propagate]
+            unsafe {
+                glb::NamedRenderbufferStorageMultisample(
+                    object.name(),
+                    SAMPLES as _,
+                    InternalFormat::ID,
+                    width as _,
+                    height as _,
+                );
+            }
+        }.map(|_| Self {
+            object,
+            format: PhantomData,
+            dimensions: [width, height],
+        })
+    }
+
+    pub fn dimensions(&self) -> [usize; 2] {
+        self.dimensions
+    }
+}
+
+impl<InternalFormat, const SAMPLES: usize> framebuffer::Image for Renderbuffer<InternalFormat, SAMPLES>
+where
+    InternalFormat: image::marker::Storable + image::marker::Uncompressed,
+{
+    type InternalFormat = InternalFormat;
+}
+
+impl<InternalFormat, const SAMPLES: usize> framebuffer::Attachable for Renderbuffer<InternalFormat, SAMPLES>
+where
+    InternalFormat: image::marker::Storable + image::marker::Uncompressed,
+{
+    fn attach(&self, framebuffer: u32, point: u32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::NamedFramebufferRenderbuffer(framebuffer, point, glb::RENDERBUFFER, self.name());
+            }
+        }
+    }
+
+    fn extent(&self) -> [usize; 2] {
+        self.dimensions
+    }
+}
@@ -0,0 +1,533 @@
+//! Fixed-function pipeline state.
+//!
+//! Depth and stencil testing, blending, face culling, rasterization mode, viewport, scissor and write masks are global
+//! context state. `PipelineState` gathers all of it into a single value which can be bound for the duration of a scope.
+//! Binding only issues calls for state that differs from the most recently applied one, and unbinding restores the
+//! state that was current before.
+
+use std::cell::RefCell;
+
+use crate::gl;
+
+/// Minimal number of draw buffers that every implementation must support.
+pub const MAX_DRAW_BUFFERS: usize = 8;
+
+/// Comparison function used by depth and stencil tests.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Comparison {
+    Never = glb::NEVER,
+    Less = glb::LESS,
+    Equal = glb::EQUAL,
+    LessEqual = glb::LEQUAL,
+    Greater = glb::GREATER,
+    NotEqual = glb::NOTEQUAL,
+    GreaterEqual = glb::GEQUAL,
+    Always = glb::ALWAYS,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlendEquation {
+    Add = glb::FUNC_ADD,
+    Subtract = glb::FUNC_SUBTRACT,
+    ReverseSubtract = glb::FUNC_REVERSE_SUBTRACT,
+    Min = glb::MIN,
+    Max = glb::MAX,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlendFactor {
+    Zero = glb::ZERO,
+    One = glb::ONE,
+    SrcColor = glb::SRC_COLOR,
+    OneMinusSrcColor = glb::ONE_MINUS_SRC_COLOR,
+    DstColor = glb::DST_COLOR,
+    OneMinusDstColor = glb::ONE_MINUS_DST_COLOR,
+    SrcAlpha = glb::SRC_ALPHA,
+    OneMinusSrcAlpha = glb::ONE_MINUS_SRC_ALPHA,
+    DstAlpha = glb::DST_ALPHA,
+    OneMinusDstAlpha = glb::ONE_MINUS_DST_ALPHA,
+    ConstantColor = glb::CONSTANT_COLOR,
+    OneMinusConstantColor = glb::ONE_MINUS_CONSTANT_COLOR,
+    ConstantAlpha = glb::CONSTANT_ALPHA,
+    OneMinusConstantAlpha = glb::ONE_MINUS_CONSTANT_ALPHA,
+    SrcAlphaSaturate = glb::SRC_ALPHA_SATURATE,
+}
+
+/// Blend equation together with its source and destination factors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BlendFunction {
+    pub equation: BlendEquation,
+    pub source: BlendFactor,
+    pub destination: BlendFactor,
+}
+
+/// Blending configuration of a single draw buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Blend {
+    pub color: BlendFunction,
+    pub alpha: BlendFunction,
+}
+
+impl Blend {
+    /// Conventional blending of non-premultiplied colors.
+    pub const ALPHA: Self = Self::uniform(BlendFunction {
+        equation: BlendEquation::Add,
+        source: BlendFactor::SrcAlpha,
+        destination: BlendFactor::OneMinusSrcAlpha,
+    });
+
+    /// Blending of premultiplied colors.
+    pub const PREMULTIPLIED_ALPHA: Self = Self::uniform(BlendFunction {
+        equation: BlendEquation::Add,
+        source: BlendFactor::One,
+        destination: BlendFactor::OneMinusSrcAlpha,
+    });
+
+    /// Additive blending.
+    pub const ADDITIVE: Self = Self::uniform(BlendFunction {
+        equation: BlendEquation::Add,
+        source: BlendFactor::One,
+        destination: BlendFactor::One,
+    });
+
+    /// Use the same function for color and alpha components.
+    pub const fn uniform(function: BlendFunction) -> Self {
+        Self { color: function, alpha: function }
+    }
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CullMode {
+    Front = glb::FRONT,
+    Back = glb::BACK,
+    FrontAndBack = glb::FRONT_AND_BACK,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrontFace {
+    Clockwise = glb::CW,
+    CounterClockwise = glb::CCW,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PolygonMode {
+    Point = glb::POINT,
+    Line = glb::LINE,
+    Fill = glb::FILL,
+}
+
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StencilOp {
+    Keep = glb::KEEP,
+    Zero = glb::ZERO,
+    Replace = glb::REPLACE,
+    Increment = glb::INCR,
+    IncrementWrap = glb::INCR_WRAP,
+    Decrement = glb::DECR,
+    DecrementWrap = glb::DECR_WRAP,
+    Invert = glb::INVERT,
+}
+
+/// Stencil test configuration for polygons of single facing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StencilFace {
+    pub function: Comparison,
+    pub reference: i32,
+    pub read_mask: u32,
+    pub write_mask: u32,
+    /// Action taken when stencil test fails.
+    pub stencil_fail: StencilOp,
+    /// Action taken when stencil test passes but depth test fails.
+    pub depth_fail: StencilOp,
+    /// Action taken when both tests pass.
+    pub pass: StencilOp,
+}
+
+impl Default for StencilFace {
+    fn default() -> Self {
+        Self {
+            function: Comparison::Always,
+            reference: 0,
+            read_mask: !0,
+            write_mask: !0,
+            stencil_fail: StencilOp::Keep,
+            depth_fail: StencilOp::Keep,
+            pass: StencilOp::Keep,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Stencil {
+    pub front: StencilFace,
+    pub back: StencilFace,
+}
+
+/// Window space rectangle used by viewport and scissor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub const fn new(x: i32, y: i32, width: usize, height: usize) -> Self {
+        Self { x, y, width, height }
+    }
+}
+
+/// Complete configuration of fixed-function stages of the pipeline.
+///
+/// `None` values disable corresponding tests. Viewport set to `None` is left untouched.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PipelineState {
+    depth_test: Option<Comparison>,
+    depth_mask: bool,
+    blend: [Option<Blend>; MAX_DRAW_BUFFERS],
+    blend_color: [f32; 4],
+    cull: Option<CullMode>,
+    front_face: FrontFace,
+    polygon_mode: PolygonMode,
+    stencil: Option<Stencil>,
+    color_mask: [bool; 4],
+    viewport: Option<Rect>,
+    scissor: Option<Rect>,
+}
+
+/// Initial state of the OpenGL context.
+impl Default for PipelineState {
+    fn default() -> Self {
+        Self {
+            depth_test: None,
+            depth_mask: true,
+            blend: [None; MAX_DRAW_BUFFERS],
+            blend_color: [0.0; 4],
+            cull: None,
+            front_face: FrontFace::CounterClockwise,
+            polygon_mode: PolygonMode::Fill,
+            stencil: None,
+            color_mask: [true; 4],
+            viewport: None,
+            scissor: None,
+        }
+    }
+}
+
+thread_local! {
+    /// State most recently applied to the context current on this thread.
+    static CURRENT: RefCell<PipelineState> = RefCell::new(PipelineState::default());
+}
+
+thread_local! {
+    /// Number of `Bind` guards alive on this thread.
+    static DEPTH: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+/// Single state setting call issued while transitioning between pipeline states.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Command {
+    Toggle(u32, bool),
+    ToggleIndexed(u32, usize, bool),
+    DepthFunc(Comparison),
+    DepthMask(bool),
+    Blend(usize, Blend),
+    BlendColor([f32; 4]),
+    CullFace(CullMode),
+    FrontFace(FrontFace),
+    PolygonMode(PolygonMode),
+    StencilFace(u32, StencilFace),
+    ColorMask([bool; 4]),
+    Viewport(Rect),
+    Scissor(Rect),
+}
+
+impl Command {
+    fn issue(self) {
+        gl::call! {
+            [panic]
+            unsafe {
+                match self {
+                    Self::Toggle(capability, true) => glb::Enable(capability),
+                    Self::Toggle(capability, false) => glb::Disable(capability),
+                    Self::ToggleIndexed(capability, index, true) => glb::Enablei(capability, index as _),
+                    Self::ToggleIndexed(capability, index, false) => glb::Disablei(capability, index as _),
+                    Self::DepthFunc(function) => glb::DepthFunc(function as _),
+                    Self::DepthMask(enabled) => glb::DepthMask(enabled as _),
+                    Self::Blend(index, blend) => {
+                        glb::BlendEquationSeparatei(index as _, blend.color.equation as _, blend.alpha.equation as _);
+                        glb::BlendFuncSeparatei(
+                            index as _,
+                            blend.color.source as _,
+                            blend.color.destination as _,
+                            blend.alpha.source as _,
+                            blend.alpha.destination as _,
+                        );
+                    }
+                    Self::BlendColor([red, green, blue, alpha]) => glb::BlendColor(red, green, blue, alpha),
+                    Self::CullFace(mode) => glb::CullFace(mode as _),
+                    Self::FrontFace(front_face) => glb::FrontFace(front_face as _),
+                    Self::PolygonMode(mode) => glb::PolygonMode(glb::FRONT_AND_BACK, mode as _),
+                    Self::StencilFace(face, state) => {
+                        glb::StencilFuncSeparate(face, state.function as _, state.reference, state.read_mask);
+                        glb::StencilOpSeparate(face, state.stencil_fail as _, state.depth_fail as _, state.pass as _);
+                        glb::StencilMaskSeparate(face, state.write_mask);
+                    }
+                    Self::ColorMask([red, green, blue, alpha]) => glb::ColorMask(red as _, green as _, blue as _, alpha as _),
+                    Self::Viewport(viewport) => glb::Viewport(viewport.x, viewport.y, viewport.width as _, viewport.height as _),
+                    Self::Scissor(scissor) => glb::Scissor(scissor.x, scissor.y, scissor.width as _, scissor.height as _),
+                }
+            }
+        }
+    }
+}
+
+impl PipelineState {
+    /// Enable depth testing with given comparison function.
+    pub fn depth_test(self, function: Comparison) -> Self {
+        Self { depth_test: Some(function), ..self }
+    }
+
+    /// Enable or disable writes into the depth buffer.
+    pub fn depth_mask(self, enabled: bool) -> Self {
+        Self { depth_mask: enabled, ..self }
+    }
+
+    /// Enable blending for draw buffer at given index.
+    pub fn blend(mut self, draw_buffer: usize, blend: Blend) -> Self {
+        if draw_buffer >= MAX_DRAW_BUFFERS {
+            panic!("draw buffer {draw_buffer} is out of bounds of {MAX_DRAW_BUFFERS} draw buffers");
+        }
+        self.blend[draw_buffer] = Some(blend);
+        self
+    }
+
+    /// Enable the same blending for all draw buffers.
+    pub fn blend_all(self, blend: Blend) -> Self {
+        Self { blend: [Some(blend); MAX_DRAW_BUFFERS], ..self }
+    }
+
+    /// Constant color used by `ConstantColor` and `ConstantAlpha` blend factors.
+    pub fn blend_color(self, color: [f32; 4]) -> Self {
+        Self { blend_color: color, ..self }
+    }
+
+    /// Enable culling of polygons with given facing.
+    pub fn cull(self, mode: CullMode) -> Self {
+        Self { cull: Some(mode), ..self }
+    }
+
+    pub fn front_face(self, front_face: FrontFace) -> Self {
+        Self { front_face, ..self }
+    }
+
+    pub fn polygon_mode(self, polygon_mode: PolygonMode) -> Self {
+        Self { polygon_mode, ..self }
+    }
+
+    /// Enable stencil testing.
+    pub fn stencil(self, stencil: Stencil) -> Self {
+        Self { stencil: Some(stencil), ..self }
+    }
+
+    /// Enable or disable writes of individual color components.
+    pub fn color_mask(self, mask: [bool; 4]) -> Self {
+        Self { color_mask: mask, ..self }
+    }
+
+    pub fn viewport(self, viewport: Rect) -> Self {
+        Self { viewport: Some(viewport), ..self }
+    }
+
+    /// Enable scissor test for given rectangle.
+    pub fn scissor(self, scissor: Rect) -> Self {
+        Self { scissor: Some(scissor), ..self }
+    }
+
+    /// Calls needed to transition from `current` to this state, issuing nothing for state that does not differ.
+    fn transition(&self, current: &Self) -> Vec<Command> {
+        let mut commands = Vec::new();
+        if self.depth_test != current.depth_test {
+            commands.push(Command::Toggle(glb::DEPTH_TEST, self.depth_test.is_some()));
+            commands.extend(self.depth_test.map(Command::DepthFunc));
+        }
+        if self.depth_mask != current.depth_mask {
+            commands.push(Command::DepthMask(self.depth_mask));
+        }
+        for (index, (blend, current)) in self.blend.iter().zip(current.blend.iter()).enumerate() {
+            if blend.is_some() != current.is_some() {
+                commands.push(Command::ToggleIndexed(glb::BLEND, index, blend.is_some()));
+            }
+            if let Some(blend) = blend.filter(|blend| Some(blend) != current.as_ref()) {
+                commands.push(Command::Blend(index, blend));
+            }
+        }
+        if self.blend_color != current.blend_color {
+            commands.push(Command::BlendColor(self.blend_color));
+        }
+        if self.cull != current.cull {
+            commands.push(Command::Toggle(glb::CULL_FACE, self.cull.is_some()));
+            commands.extend(self.cull.map(Command::CullFace));
+        }
+        if self.front_face != current.front_face {
+            commands.push(Command::FrontFace(self.front_face));
+        }
+        if self.polygon_mode != current.polygon_mode {
+            commands.push(Command::PolygonMode(self.polygon_mode));
+        }
+        if self.stencil != current.stencil {
+            commands.push(Command::Toggle(glb::STENCIL_TEST, self.stencil.is_some()));
+            let stencil = self.stencil.unwrap_or_default();
+            commands.push(Command::StencilFace(glb::FRONT, stencil.front));
+            commands.push(Command::StencilFace(glb::BACK, stencil.back));
+        }
+        if self.color_mask != current.color_mask {
+            commands.push(Command::ColorMask(self.color_mask));
+        }
+        if let Some(viewport) = self.viewport.filter(|viewport| Some(viewport) != current.viewport.as_ref()) {
+            commands.push(Command::Viewport(viewport));
+        }
+        if self.scissor != current.scissor {
+            commands.push(Command::Toggle(glb::SCISSOR_TEST, self.scissor.is_some()));
+            commands.extend(self.scissor.map(Command::Scissor));
+        }
+        commands
+    }
+
+    /// Make this state current and return previously current one.
+    fn apply(&self) -> Self {
+        CURRENT.with(|current| {
+            let previous = self.swap(&mut current.borrow_mut(), viewport);
+            self.transition(&previous).into_iter().for_each(Command::issue);
+            previous
+        })
+    }
+
+    /// Replace `current` with this state and return the replaced one.
+    ///
+    /// Viewport left untouched by `current` is queried through `query_viewport` before any call is issued, so that
+    /// the returned state restores it.
+    fn swap(&self, current: &mut Self, query_viewport: impl FnOnce() -> Rect) -> Self {
+        let mut previous = std::mem::replace(current, *self);
+        if self.viewport.is_some() && previous.viewport.is_none() {
+            previous.viewport = Some(query_viewport());
+        }
+        previous
+    }
+
+    /// Apply state until returned guard is dropped.
+    ///
+    /// Guards must be dropped in reverse order of binding, otherwise state tracked as current would not match the
+    /// context. In Debug mode dropping out of order panics.
+    pub fn bind(&self) -> Bind {
+        let depth = DEPTH.with(|depth| depth.replace(depth.get() + 1));
+        Bind { previous: self.apply(), depth }
+    }
+
+    /// Apply state for the duration of `f`.
+    pub fn bound<T>(&self, f: impl FnOnce(&Bind) -> T) -> T {
+        let bind = self.bind();
+        f(&bind)
+    }
+}
+
+/// Currently set viewport.
+fn viewport() -> Rect {
+    let mut viewport = [0; 4];
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::GetIntegerv(glb::VIEWPORT, viewport.as_mut_ptr());
+        }
+    }
+    let [x, y, width, height] = viewport;
+    Rect::new(x, y, width as _, height as _)
+}
+
+/// Scope in which given `PipelineState` is applied. Restores previously current state on drop.
+///
+/// Scopes nest: guard has to be dropped before the guard that was alive when it was bound.
+pub struct Bind {
+    previous: PipelineState,
+    /// Number of guards alive when this one was bound.
+    depth: usize,
+}
+
+impl Drop for Bind {
+    fn drop(&mut self) {
+        let depth = DEPTH.with(|depth| depth.replace(self.depth));
+        if cfg!(debug_assertions) && depth != self.depth + 1 && !std::thread::panicking() {
+            panic!("pipeline state guard bound at depth {} dropped while {depth} guards are alive", self.depth);
+        }
+        self.previous.apply();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_is_saved_before_it_is_set_and_restored_after() {
+        let original = Rect::new(0, 0, 800, 600);
+        let viewport = std::cell::Cell::new(original);
+        let mut current = PipelineState::default();
+
+        let state = PipelineState::default().viewport(Rect::new(10, 20, 64, 64));
+        let previous = state.swap(&mut current, || viewport.get());
+        assert_eq!(previous.viewport, Some(original));
+        assert_eq!(current, state);
+        viewport.set(state.viewport.unwrap());
+
+        let restored = previous.swap(&mut current, || unreachable!("viewport is known"));
+        assert_eq!(restored, state);
+        assert_eq!(current.viewport, Some(original));
+    }
+
+    #[test]
+    fn transition_toggles_and_sets_only_changed_blends() {
+        let current = PipelineState::default().blend(0, Blend::ALPHA).blend(1, Blend::ALPHA);
+        let state = PipelineState::default().blend(0, Blend::ALPHA).blend(2, Blend::ADDITIVE);
+        assert_eq!(state.transition(&current), [
+            Command::ToggleIndexed(glb::BLEND, 1, false),
+            Command::ToggleIndexed(glb::BLEND, 2, true),
+            Command::Blend(2, Blend::ADDITIVE),
+        ]);
+
+        let state = current.blend(1, Blend::PREMULTIPLIED_ALPHA);
+        assert_eq!(state.transition(&current), [Command::Blend(1, Blend::PREMULTIPLIED_ALPHA)]);
+        assert_eq!(current.transition(&current), []);
+    }
+
+    #[test]
+    fn transition_toggles_depth_test_and_sets_function_only_when_enabled() {
+        let current = PipelineState::default();
+        let state = current.depth_test(Comparison::Less).depth_mask(false);
+        assert_eq!(state.transition(&current), [
+            Command::Toggle(glb::DEPTH_TEST, true),
+            Command::DepthFunc(Comparison::Less),
+            Command::DepthMask(false),
+        ]);
+
+        let changed = state.depth_test(Comparison::GreaterEqual);
+        assert_eq!(changed.transition(&state), [
+            Command::Toggle(glb::DEPTH_TEST, true),
+            Command::DepthFunc(Comparison::GreaterEqual),
+        ]);
+        assert_eq!(current.transition(&state), [Command::Toggle(glb::DEPTH_TEST, false), Command::DepthMask(true)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "draw buffer 8 is out of bounds of 8 draw buffers")]
+    fn blend_of_draw_buffer_past_limit_panics() {
+        PipelineState::default().blend(MAX_DRAW_BUFFERS, Blend::ALPHA);
+    }
+}
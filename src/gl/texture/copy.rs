@@ -0,0 +1,218 @@
+//! Copies between images of textures and renderbuffers through `glCopyImageSubData`.
+//!
+//! Copies reinterpret texels bit for bit, so the internal formats of both images must belong to the same view class.
+//! Regions are given as origin and extent on 3 axes, the last one indexing layers or cube map faces. Axes which
+//! the image does not have must be given origin 0 and extent 1.
+
+use crate::gl;
+use gl::renderbuffer::Renderbuffer;
+use gl::texture::{self, image, storage, Texture};
+
+/// Images which can be source or destination of a copy.
+pub trait CopyImage {
+    type InternalFormat: image::marker::ViewClass;
+
+    /// Target passed alongside the image name.
+    const TARGET: u32;
+
+    fn image_name(&self) -> u32;
+
+    fn image_levels(&self) -> usize;
+
+    /// Dimensions of given level extended to 3 axes.
+    fn image_extent(&self, level: usize) -> [usize; 3];
+
+    /// Number of samples per texel, 0 for single sampled images.
+    fn image_samples(&self) -> usize;
+}
+
+/// Formats whose texels can be copied into images of format `Destination`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` texels cannot be copied into `{Destination}` image",
+    note = "copies require formats of the same view class"
+)]
+pub trait CopyCompatible<Destination> { }
+
+impl<Source, Destination> CopyCompatible<Destination> for Source
+where
+    Source: image::marker::ViewClass,
+    Destination: image::marker::ViewClass<Class = Source::Class>,
+{ }
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> CopyImage for Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: texture::Target + texture::valid::ForCopy,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::ViewClass,
+{
+    type InternalFormat = InternalFormat;
+
+    const TARGET: u32 = T::ID;
+
+    fn image_name(&self) -> u32 {
+        self.object.name()
+    }
+
+    fn image_levels(&self) -> usize {
+        self.levels()
+    }
+
+    fn image_extent(&self, level: usize) -> [usize; 3] {
+        self.level_extent(level)
+    }
+
+    fn image_samples(&self) -> usize {
+        let mut samples = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetTextureLevelParameteriv(self.object.name(), 0, glb::TEXTURE_SAMPLES, &mut samples);
+            }
+        }
+        samples as _
+    }
+}
+
+impl<InternalFormat, const SAMPLES: usize> CopyImage for Renderbuffer<InternalFormat, SAMPLES>
+where
+    InternalFormat: image::marker::ViewClass + image::marker::Storable + image::marker::Uncompressed,
+{
+    type InternalFormat = InternalFormat;
+
+    const TARGET: u32 = glb::RENDERBUFFER;
+
+    fn image_name(&self) -> u32 {
+        self.name()
+    }
+
+    fn image_levels(&self) -> usize {
+        1
+    }
+
+    fn image_extent(&self, _: usize) -> [usize; 3] {
+        let [width, height] = self.dimensions();
+        [width, height, 1]
+    }
+
+    fn image_samples(&self) -> usize {
+        let mut samples = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetNamedRenderbufferParameteriv(self.name(), glb::RENDERBUFFER_SAMPLES, &mut samples);
+            }
+        }
+        samples as _
+    }
+}
+
+fn check_region(image: &str, levels: usize, level: usize, level_extent: [usize; 3], origin: [usize; 3], extent: [usize; 3]) {
+    if level >= levels {
+        panic!("{image} level {level} is out of bounds of {levels} levels");
+    }
+    for axis in 0..3 {
+        if origin[axis].checked_add(extent[axis]).is_none_or(|end| end > level_extent[axis]) {
+            panic!(
+                "{image} region of extent {} at {} extends out of bounds of axis {axis} with size {}",
+                extent[axis],
+                origin[axis],
+                level_extent[axis],
+            );
+        }
+    }
+}
+
+/// Copy `extent` texels from `src_origin` of `src_level` of `source` into `dst_origin` of `dst_level` of `destination`.
+pub(in crate::gl) fn copy_image_sub_data<Source, Destination>(
+    source: &Source,
+    src_level: usize,
+    src_origin: [usize; 3],
+    destination: &Destination,
+    dst_level: usize,
+    dst_origin: [usize; 3],
+    extent: [usize; 3],
+)
+where
+    Source: CopyImage,
+    Destination: CopyImage,
+    Source::InternalFormat: CopyCompatible<Destination::InternalFormat>,
+{
+    let (src_samples, dst_samples) = (source.image_samples(), destination.image_samples());
+    if src_samples != dst_samples {
+        panic!("source with {src_samples} samples cannot be copied into destination with {dst_samples} samples");
+    }
+    check_region("source", source.image_levels(), src_level, source.image_extent(src_level), src_origin, extent);
+    check_region("destination", destination.image_levels(), dst_level, destination.image_extent(dst_level), dst_origin, extent);
+    let [src_x, src_y, src_z] = src_origin;
+    let [dst_x, dst_y, dst_z] = dst_origin;
+    let [width, height, depth] = extent;
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::CopyImageSubData(
+                source.image_name(),
+                Source::TARGET,
+                src_level as _,
+                src_x as _,
+                src_y as _,
+                src_z as _,
+                destination.image_name(),
+                Destination::TARGET,
+                dst_level as _,
+                dst_x as _,
+                dst_y as _,
+                dst_z as _,
+                width as _,
+                height as _,
+                depth as _,
+            );
+        }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: texture::Target + texture::valid::ForCopy,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::ViewClass,
+{
+    /// Copy `extent` texels starting at `src_origin` of `src_level` of `source` into `dst_origin` of `dst_level`.
+    ///
+    /// Cube map faces and array layers are addressed by the last axis.
+    pub fn copy_region_from<Source>(
+        &mut self,
+        source: &Source,
+        src_level: usize,
+        src_origin: [usize; 3],
+        dst_level: usize,
+        dst_origin: [usize; 3],
+        extent: [usize; 3],
+    )
+    where
+        Source: CopyImage,
+        Source::InternalFormat: CopyCompatible<InternalFormat>,
+    {
+        copy_image_sub_data(source, src_level, src_origin, &*self, dst_level, dst_origin, extent);
+    }
+}
+
+impl<InternalFormat, const SAMPLES: usize> Renderbuffer<InternalFormat, SAMPLES>
+where
+    InternalFormat: image::marker::ViewClass + image::marker::Storable + image::marker::Uncompressed,
+{
+    /// Copy `extent` texels starting at `src_origin` of `src_level` of `source` into `dst_origin`.
+    pub fn copy_region_from<Source>(
+        &mut self,
+        source: &Source,
+        src_level: usize,
+        src_origin: [usize; 3],
+        dst_origin: [usize; 3],
+        extent: [usize; 3],
+    )
+    where
+        Source: CopyImage,
+        Source::InternalFormat: CopyCompatible<InternalFormat>,
+    {
+        copy_image_sub_data(source, src_level, src_origin, &*self, 0, dst_origin, extent);
+    }
+}
@@ -0,0 +1,77 @@
+//! Filters applied when texture is magnified or minified during sampling.
+//!
+//! Minification filters which read mipmaps can only be used with textures that contain them.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+
+/// Filter used when texel covers more than one fragment.
+pub trait Mag {
+    const ID: u32;
+}
+
+/// Filter used when fragment covers more than one texel.
+pub trait Min {
+    const ID: u32;
+}
+
+/// Sample nearest texel.
+#[derive(Clone, Copy, Debug)]
+pub struct Nearest;
+
+/// Interpolate between nearest texels.
+#[derive(Clone, Copy, Debug)]
+pub struct Linear;
+
+/// Sample nearest texel of nearest mipmap.
+#[derive(Clone, Copy, Debug)]
+pub struct NearestMipmapNearest;
+
+/// Interpolate texels of nearest mipmap.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearMipmapNearest;
+
+/// Sample nearest texels of two nearest mipmaps and interpolate between them.
+#[derive(Clone, Copy, Debug)]
+pub struct NearestMipmapLinear;
+
+/// Trilinear filtering.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearMipmapLinear;
+
+gl::impl_token! { Nearest as Mag => NEAREST }
+gl::impl_token! { Linear  as Mag => LINEAR  }
+
+gl::impl_token! { Nearest              as Min => NEAREST                }
+gl::impl_token! { Linear               as Min => LINEAR                 }
+gl::impl_token! { NearestMipmapNearest as Min => NEAREST_MIPMAP_NEAREST }
+gl::impl_token! { LinearMipmapNearest  as Min => LINEAR_MIPMAP_NEAREST  }
+gl::impl_token! { NearestMipmapLinear  as Min => NEAREST_MIPMAP_LINEAR  }
+gl::impl_token! { LinearMipmapLinear   as Min => LINEAR_MIPMAP_LINEAR   }
+
+/// Minification filters usable with texture whose storage does (`true`) or does not (`false`) contain mipmaps.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` filter cannot be used with texture where `MIPMAPS = {MIPMAPS}`",
+    note = "mipmapped filters require texture created with mipmaps"
+)]
+#[hi::marker]
+pub trait ForMipmaps<const MIPMAPS: bool>: Min { }
+
+hi::denmark! { Nearest as ForMipmaps<false>, ForMipmaps<true> }
+hi::denmark! { Linear as ForMipmaps<false>, ForMipmaps<true> }
+hi::denmark! { NearestMipmapNearest as ForMipmaps<true> }
+hi::denmark! { LinearMipmapNearest as ForMipmaps<true> }
+hi::denmark! { NearestMipmapLinear as ForMipmaps<true> }
+hi::denmark! { LinearMipmapLinear as ForMipmaps<true> }
+
+/// Handling of texture coordinates outside of `[0, 1]` range.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Wrap {
+    Repeat = glb::REPEAT,
+    MirroredRepeat = glb::MIRRORED_REPEAT,
+    ClampToEdge = glb::CLAMP_TO_EDGE,
+    ClampToBorder = glb::CLAMP_TO_BORDER,
+    MirrorClampToEdge = glb::MIRROR_CLAMP_TO_EDGE,
+}
@@ -15,22 +15,107 @@ pub mod marker {
 
     pub trait FormatType { }
 
+    /// Format types whose images are transferred as individual pixels.
+    #[diagnostic::on_unimplemented(
+        message = "images of `{Self}` formats cannot be uploaded pixel by pixel",
+        note = "images of compressed formats are uploaded with `compressed_sub_image_*`"
+    )]
+    #[hi::marker]
+    pub trait PixelTransfer: FormatType { }
+
     
-    #[hi::mark(FormatType)]
+    #[hi::mark(FormatType, PixelTransfer)]
     pub enum Aggregate { }
     
-    #[hi::mark(FormatType)]
+    #[hi::mark(FormatType, PixelTransfer)]
     pub enum Special { }
 
+    /// Format storing texels in fixed size blocks.
+    #[hi::mark(FormatType)]
+    pub enum Compressed { }
+
+    /// How texels of the format are read by samplers.
+    pub trait Sampling {
+        /// Target of sampler which reads texture of target `T`.
+        type Target<T: gl::texture::Target>: glsl::sampler::Target;
+        /// Comparison mode of sampler objects which can read such texels, either `Plain` or `Comparing`.
+        type Mode: Sampling;
+    }
+
+    /// Texels are returned as stored.
+    pub enum Plain { }
+
+    impl Sampling for Plain {
+        type Target<T: gl::texture::Target> = T;
+        type Mode = Self;
+    }
+
+    /// Texels are compared against reference value, which only shadow samplers provide.
+    pub enum Comparing { }
+
+    impl Sampling for Comparing {
+        type Target<T: gl::texture::Target> = glsl::sampler::Shadow<T>;
+        type Mode = Self;
+    }
+
+    /// Stencil indices of depth-stencil texels are returned instead of depth.
+    pub enum StencilIndex { }
+
+    impl Sampling for StencilIndex {
+        type Target<T: gl::texture::Target> = T;
+        type Mode = Plain;
+    }
+
     pub trait Format {
         const ID: u32;
         type Output: glsl::sampler::Output;
         type Composition: FormatType;
         type BaseFormat: BaseFormat;
         type ComponentType: gl::Type;
+        type Sampling: Sampling;
     }
 
+    /// Formats which textures can be created with.
+    ///
+    /// Excludes `Compared` and `StencilSampled`, which stand for sampling modes that only
+    /// `Texture::compare` and `Texture::sample_stencil` enable.
+    #[diagnostic::on_unimplemented(
+        message = "texture of `{Self}` format cannot be created directly",
+        note = "textures sampled with comparison are obtained from `Texture::compare`, those sampling stencil from `Texture::sample_stencil`"
+    )]
+    pub trait Storable: Format<Sampling = Plain> { }
+
+    impl<F> Storable for F where F: Format<Sampling = Plain> { }
+
+    /// Formats whose images can be uploaded pixel by pixel.
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` images cannot be uploaded pixel by pixel",
+        note = "images of compressed formats are uploaded with `compressed_sub_image_*`"
+    )]
+    pub trait Uncompressed: Format { }
+
+    impl<F> Uncompressed for F where F: Format, F::Composition: PixelTransfer { }
+
     pub trait AggregateFormat: Format<Composition = Aggregate> { }
+
+    /// Formats grouped into classes whose members can reinterpret each other's storage through texture views.
+    pub trait ViewClass: Format {
+        type Class;
+    }
+
+    pub trait CompressedFormat: Format<Composition = Compressed> {
+        /// Width of a block in texels.
+        const BLOCK_WIDTH: usize;
+        /// Height of a block in texels.
+        const BLOCK_HEIGHT: usize;
+        /// Size of a single block in bytes.
+        const BLOCK_BYTES: usize;
+
+        /// Bytes taken by image of given width and height, where partial blocks at the edges are stored whole.
+        fn image_size(width: usize, height: usize) -> usize {
+            width.div_ceil(Self::BLOCK_WIDTH) * height.div_ceil(Self::BLOCK_HEIGHT) * Self::BLOCK_BYTES
+        }
+    }
 }
 
 pub struct Format<Components, ComponentType, Interpretation = format::UNorm>(PhantomData<(Components, ComponentType, Interpretation)>)
@@ -39,9 +124,35 @@ where
     Interpretation: format::Interpretation,
 ;
 
+/// Classes of formats compatible for texture views, see `ViewClass`.
+pub mod view {
+    /// Uncompressed color formats with texels of `BITS` size.
+    pub enum Bits<const BITS: usize> { }
+
+    macro_rules! view_classes {
+        ($($class:ident),+) => {
+            $(
+                #[allow(non_camel_case_types)]
+                pub enum $class { }
+            )+
+        };
+    }
+
+    view_classes! { S3TC_DXT1_RGB, S3TC_DXT1_RGBA, S3TC_DXT3_RGBA, S3TC_DXT5_RGBA }
+    view_classes! { RGTC1_RED, RGTC2_RG, BPTC_UNORM, BPTC_FLOAT }
+    view_classes! { EAC_R11, EAC_RG11, ETC2_RGB, ETC2_RGBA, ETC2_EAC_RGBA }
+}
+
 pub mod format {
     use super::*;
 
+    macro_rules! components_count {
+        (R) => { 1 };
+        (RG) => { 2 };
+        (RGB) => { 3 };
+        (RGBA) => { 4 };
+    }
+
     macro_rules! map_base_format {
         (R) => { RED };
         ($ty:ty) => { $ty };
@@ -50,6 +161,18 @@ pub mod format {
     pub enum RED { }
     impl marker::BaseFormat for RED { const ID: u32 = glb::RED   ; type Components = components::R; }
 
+    #[allow(non_camel_case_types)]
+    pub enum DEPTH_COMPONENT { }
+    impl marker::BaseFormat for DEPTH_COMPONENT { const ID: u32 = glb::DEPTH_COMPONENT; type Components = components::R; }
+
+    #[allow(non_camel_case_types)]
+    pub enum STENCIL_INDEX { }
+    impl marker::BaseFormat for STENCIL_INDEX { const ID: u32 = glb::STENCIL_INDEX; type Components = components::R; }
+
+    #[allow(non_camel_case_types)]
+    pub enum DEPTH_STENCIL { }
+    impl marker::BaseFormat for DEPTH_STENCIL { const ID: u32 = glb::DEPTH_STENCIL; type Components = components::RG; }
+
     pub struct Rev<T>(PhantomData<T>) where T: Interpretation;
 
     pub use components::{Components, RG, RGB, RGBA};
@@ -147,8 +270,13 @@ pub mod format {
                     type ComponentType = $component_type;
                     type Composition = marker::Aggregate;
                     type Output = <ty::suffix_to_type!() as ty::Interpretation>::Output;
+                    type Sampling = marker::Plain;
                     // type Kind = gl::types::Integer;
                 }
+
+                impl marker::ViewClass for Format<components::$components, $component_type, suffix_to_type!()> {
+                    type Class = view::Bits<{ $size * components_count!($components) }>;
+                }
             });
         };
         ($components:ident, $component_type:ty, $size:literal, $ty_suffix:ident) => {
@@ -159,65 +287,353 @@ pub mod format {
                     type ComponentType = $component_type;
                     type Composition = marker::Aggregate;
                     type Output = <suffix_to_type!($ty_suffix) as ty::Interpretation>::Output;
+                    type Sampling = marker::Plain;
                     // type Kind = gl::types::Integer;
                 }
+
+                impl marker::ViewClass for Format<components::$components, $component_type, suffix_to_type!($ty_suffix)> {
+                    type Class = view::Bits<{ $size * components_count!($components) }>;
+                }
             });
         };
     }
 
-    impl_format! { R,    u8,    8            }
-    impl_format! { R,    i8,    8,    _SNORM }
-    impl_format! { R,    u16,   16           }
-    impl_format! { R,    i16,   16,   _SNORM }
-    impl_format! { RG,   u8,    8            }
-    impl_format! { RG,   i8,    8,    _SNORM }
-    impl_format! { RG,   u16,   16           }
-    impl_format! { RG,   i16,   16,   _SNORM }
-    // impl_format! { RGB,  4               }
-    // impl_format! { RGB,  5               }
-    impl_format! { RGB,  u8, 8              }
-    impl_format! { RGB,  i8,    8,    _SNORM }
-    // impl_format! { RGB,  10              }
-    // impl_format! { RGB,  12              }
-    impl_format! { RGB,  u16, 16             }
-    impl_format! { RGB,  i16, 16,     _SNORM }
-    // impl_format! { RGBA, 2               }
-    // impl_format! { RGBA, 4               }
-    impl_format! { RGBA, u8, 8              }
-    impl_format! { RGBA, i8, 8,      _SNORM }
-    // impl_format! { RGBA, 12              }
-    impl_format! { RGBA, u16, 16             }
-    impl_format! { RGBA, i16, 16,     _SNORM }
-    impl_format! { R,    gl::types::float16, 16,     F      }
-    impl_format! { RG,   gl::types::float16, 16,     F      }
-    impl_format! { RGB,  gl::types::float16, 16,     F      }
-    impl_format! { RGBA, gl::types::float16, 16,     F      }
-    impl_format! { R,    f32, 32,  F     }
-    impl_format! { RG,   f32, 32,  F     }
-    impl_format! { RGB,  f32, 32,  F     }
-    impl_format! { RGBA, f32, 32,  F     }
-    impl_format! { R,    u8, 8,    I      }
-    impl_format! { R,    i8, 8,    UI     }
-    impl_format! { R,    u16, 16,  I      }
-    impl_format! { R,    i16, 16,  UI     }
-    impl_format! { R,    u32, 32,  I      }
-    impl_format! { R,    i32, 32,  UI     }
-    impl_format! { RG,   u8, 8,       I      }
-    impl_format! { RG,   i8, 8,       UI     }
-    impl_format! { RG,   u16, 16,      I      }
-    impl_format! { RG,   i16, 16,      UI     }
-    impl_format! { RG,   u32, 32,      I      }
-    impl_format! { RG,   i32, 32,      UI     }
-    impl_format! { RGB,  u8,  8,     I      }
-    impl_format! { RGB,  i8,  8,     UI     }
-    impl_format! { RGB,  u16,  16,    I      }
-    impl_format! { RGB,  i16,  16,    UI     }
-    impl_format! { RGB,  u32,  32,    I      }
-    impl_format! { RGB,  i32,  32,    UI     }
-    impl_format! { RGBA, u8,  8,     I      }
-    impl_format! { RGBA, i8,  8,     UI     }
-    impl_format! { RGBA, u16,   16,   I      }
-    impl_format! { RGBA, i16,   16,   UI     }
-    impl_format! { RGBA, u32,   32,   I      }
-    impl_format! { RGBA, i32,   32,   UI     }
+    impl_format! { R,    u8,  8            }
+    impl_format! { R,    i8,  8,   _SNORM  }
+    impl_format! { R,    u16, 16           }
+    impl_format! { R,    i16, 16,  _SNORM  }
+    impl_format! { RG,   u8,  8            }
+    impl_format! { RG,   i8,  8,   _SNORM  }
+    impl_format! { RG,   u16, 16           }
+    impl_format! { RG,   i16, 16,  _SNORM  }
+    impl_format! { RGB,  u8,  8            }
+    impl_format! { RGB,  i8,  8,   _SNORM  }
+    impl_format! { RGB,  u16, 16           }
+    impl_format! { RGB,  i16, 16,  _SNORM  }
+    impl_format! { RGBA, u8,  8            }
+    impl_format! { RGBA, i8,  8,   _SNORM  }
+    impl_format! { RGBA, u16, 16           }
+    impl_format! { RGBA, i16, 16,  _SNORM  }
+    impl_format! { R,    gl::types::float16, 16, F }
+    impl_format! { RG,   gl::types::float16, 16, F }
+    impl_format! { RGB,  gl::types::float16, 16, F }
+    impl_format! { RGBA, gl::types::float16, 16, F }
+    impl_format! { R,    f32, 32,  F       }
+    impl_format! { RG,   f32, 32,  F       }
+    impl_format! { RGB,  f32, 32,  F       }
+    impl_format! { RGBA, f32, 32,  F       }
+    impl_format! { R,    i8,  8,   I       }
+    impl_format! { R,    u8,  8,   UI      }
+    impl_format! { R,    i16, 16,  I       }
+    impl_format! { R,    u16, 16,  UI      }
+    impl_format! { R,    i32, 32,  I       }
+    impl_format! { R,    u32, 32,  UI      }
+    impl_format! { RG,   i8,  8,   I       }
+    impl_format! { RG,   u8,  8,   UI      }
+    impl_format! { RG,   i16, 16,  I       }
+    impl_format! { RG,   u16, 16,  UI      }
+    impl_format! { RG,   i32, 32,  I       }
+    impl_format! { RG,   u32, 32,  UI      }
+    impl_format! { RGB,  i8,  8,   I       }
+    impl_format! { RGB,  u8,  8,   UI      }
+    impl_format! { RGB,  i16, 16,  I       }
+    impl_format! { RGB,  u16, 16,  UI      }
+    impl_format! { RGB,  i32, 32,  I       }
+    impl_format! { RGB,  u32, 32,  UI      }
+    impl_format! { RGBA, i8,  8,   I       }
+    impl_format! { RGBA, u8,  8,   UI      }
+    impl_format! { RGBA, i16, 16,  I       }
+    impl_format! { RGBA, u16, 16,  UI      }
+    impl_format! { RGBA, i32, 32,  I       }
+    impl_format! { RGBA, u32, 32,  UI      }
+
+    /// Formats whose components differ in size, are packed together or are not stored as plain integers or floats.
+    macro_rules! impl_sized_format {
+        ($format:ident: $base_format:ty, $component_type:ty, $composition:ty => $output:ty $(, view $bits:literal)?) => {
+            #[allow(non_camel_case_types)]
+            pub enum $format { }
+
+            impl marker::Format for $format {
+                const ID: u32 = ::glb::$format;
+                type BaseFormat = $base_format;
+                type ComponentType = $component_type;
+                type Composition = $composition;
+                type Output = $output;
+                type Sampling = marker::Plain;
+            }
+
+            $(
+                impl marker::ViewClass for $format {
+                    type Class = view::Bits<$bits>;
+                }
+            )?
+        };
+    }
+
+    impl_sized_format! { R3_G3_B2:       RGB,  u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGB4:           RGB,  u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGB5:           RGB,  u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGB565:         RGB,  gl::types::urgb565, marker::Special => f32 }
+    impl_sized_format! { RGB10:          RGB,  u16, marker::Aggregate => f32 }
+    impl_sized_format! { RGB12:          RGB,  u16, marker::Aggregate => f32 }
+    impl_sized_format! { RGBA2:          RGBA, u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGBA4:          RGBA, u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGB5_A1:        RGBA, u8,  marker::Aggregate => f32 }
+    impl_sized_format! { RGBA12:         RGBA, u16, marker::Aggregate => f32 }
+    impl_sized_format! { SRGB8:          RGB,  u8,  marker::Aggregate => f32, view 24 }
+    impl_sized_format! { SRGB8_ALPHA8:   RGBA, u8,  marker::Aggregate => f32, view 32 }
+    impl_sized_format! { RGB10_A2:       RGBA, gl::types::urgb10a2,   marker::Special => f32, view 32 }
+    impl_sized_format! { RGB10_A2UI:     RGBA, gl::types::urgb10a2,   marker::Special => u32, view 32 }
+    impl_sized_format! { R11F_G11F_B10F: RGB,  gl::types::u10f10f11f, marker::Special => f32, view 32 }
+    impl_sized_format! { RGB9_E5:        RGB,  f32, marker::Special   => f32, view 32 }
+
+    macro_rules! impl_depth_stencil_format {
+        ($format:ident: $base_format:ty, $component_type:ty, $composition:ty => $output:ty) => {
+            #[allow(non_camel_case_types)]
+            pub enum $format { }
+
+            impl marker::Format for $format {
+                const ID: u32 = ::glb::$format;
+                type BaseFormat = $base_format;
+                type ComponentType = $component_type;
+                type Composition = $composition;
+                type Output = $output;
+                type Sampling = marker::Plain;
+            }
+
+            impl marker::ViewClass for $format {
+                type Class = Self;
+            }
+        };
+    }
+
+    impl_depth_stencil_format! { DEPTH_COMPONENT16:  DEPTH_COMPONENT, u16, marker::Aggregate => f32 }
+    impl_depth_stencil_format! { DEPTH_COMPONENT24:  DEPTH_COMPONENT, u32, marker::Aggregate => f32 }
+    impl_depth_stencil_format! { DEPTH_COMPONENT32F: DEPTH_COMPONENT, f32, marker::Aggregate => f32 }
+    impl_depth_stencil_format! { DEPTH24_STENCIL8:   DEPTH_STENCIL,   gl::types::u24u8, marker::Special => f32 }
+    impl_depth_stencil_format! { DEPTH32F_STENCIL8:  DEPTH_STENCIL,   gl::types::f32u24u8, marker::Special => f32 }
+    impl_depth_stencil_format! { STENCIL_INDEX8:     STENCIL_INDEX,   u8,  marker::Aggregate => u32 }
+}
+
+pub mod compressed {
+    use super::*;
+    use format::{RED, RG, RGB, RGBA};
+
+    // S3TC formats are provided by `EXT_texture_compression_s3tc` and `EXT_texture_sRGB` rather than core OpenGL.
+    const COMPRESSED_RGB_S3TC_DXT1_EXT: u32 = 0x83F0;
+    const COMPRESSED_RGBA_S3TC_DXT1_EXT: u32 = 0x83F1;
+    const COMPRESSED_RGBA_S3TC_DXT3_EXT: u32 = 0x83F2;
+    const COMPRESSED_RGBA_S3TC_DXT5_EXT: u32 = 0x83F3;
+    const COMPRESSED_SRGB_S3TC_DXT1_EXT: u32 = 0x8C4C;
+    const COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT: u32 = 0x8C4D;
+    const COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT: u32 = 0x8C4E;
+    const COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT: u32 = 0x8C4F;
+
+    macro_rules! impl_compressed_format {
+        ($format:ident = $id:expr; $base_format:ty, $width:literal x $height:literal, $bytes:literal in $class:ident => $output:ty) => {
+            #[allow(non_camel_case_types)]
+            pub enum $format { }
+
+            impl marker::Format for $format {
+                const ID: u32 = $id;
+                type BaseFormat = $base_format;
+                type ComponentType = u8;
+                type Composition = marker::Compressed;
+                type Output = $output;
+                type Sampling = marker::Plain;
+            }
+
+            impl marker::CompressedFormat for $format {
+                const BLOCK_WIDTH: usize = $width;
+                const BLOCK_HEIGHT: usize = $height;
+                const BLOCK_BYTES: usize = $bytes;
+            }
+
+            impl marker::ViewClass for $format {
+                type Class = view::$class;
+            }
+        };
+    }
+
+    impl_compressed_format! { COMPRESSED_RGB_S3TC_DXT1        = COMPRESSED_RGB_S3TC_DXT1_EXT;        RGB,  4 x 4,  8 in S3TC_DXT1_RGB  => f32 }
+    impl_compressed_format! { COMPRESSED_RGBA_S3TC_DXT1       = COMPRESSED_RGBA_S3TC_DXT1_EXT;       RGBA, 4 x 4,  8 in S3TC_DXT1_RGBA => f32 }
+    impl_compressed_format! { COMPRESSED_RGBA_S3TC_DXT3       = COMPRESSED_RGBA_S3TC_DXT3_EXT;       RGBA, 4 x 4, 16 in S3TC_DXT3_RGBA => f32 }
+    impl_compressed_format! { COMPRESSED_RGBA_S3TC_DXT5       = COMPRESSED_RGBA_S3TC_DXT5_EXT;       RGBA, 4 x 4, 16 in S3TC_DXT5_RGBA => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB_S3TC_DXT1       = COMPRESSED_SRGB_S3TC_DXT1_EXT;       RGB,  4 x 4,  8 in S3TC_DXT1_RGB  => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB_ALPHA_S3TC_DXT1 = COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT; RGBA, 4 x 4,  8 in S3TC_DXT1_RGBA => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB_ALPHA_S3TC_DXT3 = COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT; RGBA, 4 x 4, 16 in S3TC_DXT3_RGBA => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB_ALPHA_S3TC_DXT5 = COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT; RGBA, 4 x 4, 16 in S3TC_DXT5_RGBA => f32 }
+
+    impl_compressed_format! { COMPRESSED_RED_RGTC1        = glb::COMPRESSED_RED_RGTC1;        RED, 4 x 4,  8 in RGTC1_RED      => f32 }
+    impl_compressed_format! { COMPRESSED_SIGNED_RED_RGTC1 = glb::COMPRESSED_SIGNED_RED_RGTC1; RED, 4 x 4,  8 in RGTC1_RED      => f32 }
+    impl_compressed_format! { COMPRESSED_RG_RGTC2         = glb::COMPRESSED_RG_RGTC2;         RG,  4 x 4, 16 in RGTC2_RG       => f32 }
+    impl_compressed_format! { COMPRESSED_SIGNED_RG_RGTC2  = glb::COMPRESSED_SIGNED_RG_RGTC2;  RG,  4 x 4, 16 in RGTC2_RG       => f32 }
+
+    impl_compressed_format! { COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT = glb::COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT; RGB,  4 x 4, 16 in BPTC_FLOAT     => f32 }
+    impl_compressed_format! { COMPRESSED_RGB_BPTC_SIGNED_FLOAT   = glb::COMPRESSED_RGB_BPTC_SIGNED_FLOAT;   RGB,  4 x 4, 16 in BPTC_FLOAT     => f32 }
+    impl_compressed_format! { COMPRESSED_RGBA_BPTC_UNORM         = glb::COMPRESSED_RGBA_BPTC_UNORM;         RGBA, 4 x 4, 16 in BPTC_UNORM     => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB_ALPHA_BPTC_UNORM   = glb::COMPRESSED_SRGB_ALPHA_BPTC_UNORM;   RGBA, 4 x 4, 16 in BPTC_UNORM     => f32 }
+
+    impl_compressed_format! { COMPRESSED_RGB8_ETC2                      = glb::COMPRESSED_RGB8_ETC2;                      RGB,  4 x 4,  8 in ETC2_RGB       => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB8_ETC2                     = glb::COMPRESSED_SRGB8_ETC2;                     RGB,  4 x 4,  8 in ETC2_RGB       => f32 }
+    impl_compressed_format! { COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2  = glb::COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2;  RGBA, 4 x 4,  8 in ETC2_RGBA      => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2 = glb::COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2; RGBA, 4 x 4,  8 in ETC2_RGBA      => f32 }
+    impl_compressed_format! { COMPRESSED_RGBA8_ETC2_EAC                 = glb::COMPRESSED_RGBA8_ETC2_EAC;                 RGBA, 4 x 4, 16 in ETC2_EAC_RGBA  => f32 }
+    impl_compressed_format! { COMPRESSED_SRGB8_ALPHA8_ETC2_EAC          = glb::COMPRESSED_SRGB8_ALPHA8_ETC2_EAC;          RGBA, 4 x 4, 16 in ETC2_EAC_RGBA  => f32 }
+    impl_compressed_format! { COMPRESSED_R11_EAC                        = glb::COMPRESSED_R11_EAC;                        RED,  4 x 4,  8 in EAC_R11        => f32 }
+    impl_compressed_format! { COMPRESSED_SIGNED_R11_EAC                 = glb::COMPRESSED_SIGNED_R11_EAC;                 RED,  4 x 4,  8 in EAC_R11        => f32 }
+    impl_compressed_format! { COMPRESSED_RG11_EAC                       = glb::COMPRESSED_RG11_EAC;                       RG,   4 x 4, 16 in EAC_RG11       => f32 }
+    impl_compressed_format! { COMPRESSED_SIGNED_RG11_EAC                = glb::COMPRESSED_SIGNED_RG11_EAC;                RG,   4 x 4, 16 in EAC_RG11       => f32 }
+
+    /// Block compression formats under their DirectX names.
+    pub type BC1 = COMPRESSED_RGBA_S3TC_DXT1;
+    pub type BC2 = COMPRESSED_RGBA_S3TC_DXT3;
+    pub type BC3 = COMPRESSED_RGBA_S3TC_DXT5;
+    pub type BC4 = COMPRESSED_RED_RGTC1;
+    pub type BC5 = COMPRESSED_RG_RGTC2;
+    pub type BC6H = COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT;
+    pub type BC7 = COMPRESSED_RGBA_BPTC_UNORM;
+}
+
+/// Depth format `F` sampled with comparison against reference value, as required by shadow samplers.
+pub struct Compared<F>(PhantomData<F>) where F: marker::Format;
+
+impl<F> marker::Format for Compared<F>
+where
+    F: marker::Format,
+{
+    const ID: u32 = F::ID;
+    type BaseFormat = F::BaseFormat;
+    type Output = f32;
+    type Composition = F::Composition;
+    type ComponentType = F::ComponentType;
+    type Sampling = marker::Comparing;
+}
+
+impl<F> marker::ViewClass for Compared<F>
+where
+    F: marker::ViewClass,
+{
+    type Class = F::Class;
+}
+
+/// Depth-stencil format `F` whose stencil indices are sampled instead of depth, as unsigned integers.
+pub struct StencilSampled<F>(PhantomData<F>) where F: marker::Format;
+
+impl<F> marker::Format for StencilSampled<F>
+where
+    F: marker::Format,
+{
+    const ID: u32 = F::ID;
+    type BaseFormat = F::BaseFormat;
+    type Output = u32;
+    type Composition = F::Composition;
+    type ComponentType = F::ComponentType;
+    type Sampling = marker::StencilIndex;
+}
+
+impl<F> marker::ViewClass for StencilSampled<F>
+where
+    F: marker::ViewClass,
+{
+    type Class = F::Class;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::gl::types::{float16, urgb565, urgb10a2, u10f10f11f, u24u8, f32u24u8};
+    use format::{components::*, *};
+
+    trait Same<T> { }
+    impl<T> Same<T> for T { }
+
+    const fn require_format<F, Output, ComponentType>(id: u32) -> bool
+    where
+        F: marker::Format,
+        F::Output: Same<Output>,
+        F::ComponentType: Same<ComponentType>,
+    {
+        F::ID == id
+    }
+
+    macro_rules! format_table {
+        ($($format:ty => $id:ident, $output:ty, $component_type:ty;)*) => {
+            $(const _: () = assert!(require_format::<$format, $output, $component_type>(::glb::$id));)*
+        };
+    }
+
+    format_table! {
+        Format<R, u8>                => R8,              f32, u8;
+        Format<R, i8, SNorm>         => R8_SNORM,        f32, i8;
+        Format<R, u16>               => R16,             f32, u16;
+        Format<R, i16, SNorm>        => R16_SNORM,       f32, i16;
+        Format<RG, u8>               => RG8,             f32, u8;
+        Format<RG, i8, SNorm>        => RG8_SNORM,       f32, i8;
+        Format<RG, u16>              => RG16,            f32, u16;
+        Format<RG, i16, SNorm>       => RG16_SNORM,      f32, i16;
+        R3_G3_B2                     => R3_G3_B2,        f32, u8;
+        RGB4                         => RGB4,            f32, u8;
+        RGB5                         => RGB5,            f32, u8;
+        RGB565                       => RGB565,          f32, urgb565;
+        Format<RGB, u8>              => RGB8,            f32, u8;
+        Format<RGB, i8, SNorm>       => RGB8_SNORM,      f32, i8;
+        RGB10                        => RGB10,           f32, u16;
+        RGB12                        => RGB12,           f32, u16;
+        Format<RGB, u16>             => RGB16,           f32, u16;
+        Format<RGB, i16, SNorm>      => RGB16_SNORM,     f32, i16;
+        RGBA2                        => RGBA2,           f32, u8;
+        RGBA4                        => RGBA4,           f32, u8;
+        RGB5_A1                      => RGB5_A1,         f32, u8;
+        Format<RGBA, u8>             => RGBA8,           f32, u8;
+        Format<RGBA, i8, SNorm>      => RGBA8_SNORM,     f32, i8;
+        RGB10_A2                     => RGB10_A2,        f32, urgb10a2;
+        RGB10_A2UI                   => RGB10_A2UI,      u32, urgb10a2;
+        RGBA12                       => RGBA12,          f32, u16;
+        Format<RGBA, u16>            => RGBA16,          f32, u16;
+        Format<RGBA, i16, SNorm>     => RGBA16_SNORM,    f32, i16;
+        SRGB8                        => SRGB8,           f32, u8;
+        SRGB8_ALPHA8                 => SRGB8_ALPHA8,    f32, u8;
+        Format<R, float16, F>        => R16F,            f32, float16;
+        Format<RG, float16, F>       => RG16F,           f32, float16;
+        Format<RGB, float16, F>      => RGB16F,          f32, float16;
+        Format<RGBA, float16, F>     => RGBA16F,         f32, float16;
+        Format<R, f32, F>            => R32F,            f32, f32;
+        Format<RG, f32, F>           => RG32F,           f32, f32;
+        Format<RGB, f32, F>          => RGB32F,          f32, f32;
+        Format<RGBA, f32, F>         => RGBA32F,         f32, f32;
+        R11F_G11F_B10F               => R11F_G11F_B10F,  f32, u10f10f11f;
+        RGB9_E5                      => RGB9_E5,         f32, f32;
+        Format<R, i8, I>             => R8I,             i32, i8;
+        Format<R, u8, UI>            => R8UI,            u32, u8;
+        Format<R, i16, I>            => R16I,            i32, i16;
+        Format<R, u16, UI>           => R16UI,           u32, u16;
+        Format<R, i32, I>            => R32I,            i32, i32;
+        Format<R, u32, UI>           => R32UI,           u32, u32;
+        Format<RG, i8, I>            => RG8I,            i32, i8;
+        Format<RG, u8, UI>           => RG8UI,           u32, u8;
+        Format<RG, i16, I>           => RG16I,           i32, i16;
+        Format<RG, u16, UI>          => RG16UI,          u32, u16;
+        Format<RG, i32, I>           => RG32I,           i32, i32;
+        Format<RG, u32, UI>          => RG32UI,          u32, u32;
+        Format<RGB, i8, I>           => RGB8I,           i32, i8;
+        Format<RGB, u8, UI>          => RGB8UI,          u32, u8;
+        Format<RGB, i16, I>          => RGB16I,          i32, i16;
+        Format<RGB, u16, UI>         => RGB16UI,         u32, u16;
+        Format<RGB, i32, I>          => RGB32I,          i32, i32;
+        Format<RGB, u32, UI>         => RGB32UI,         u32, u32;
+        Format<RGBA, i8, I>          => RGBA8I,          i32, i8;
+        Format<RGBA, u8, UI>         => RGBA8UI,         u32, u8;
+        Format<RGBA, i16, I>         => RGBA16I,         i32, i16;
+        Format<RGBA, u16, UI>        => RGBA16UI,        u32, u16;
+        Format<RGBA, i32, I>         => RGBA32I,         i32, i32;
+        Format<RGBA, u32, UI>        => RGBA32UI,        u32, u32;
+        DEPTH_COMPONENT16            => DEPTH_COMPONENT16,  f32, u16;
+        DEPTH_COMPONENT24            => DEPTH_COMPONENT24,  f32, u32;
+        DEPTH_COMPONENT32F           => DEPTH_COMPONENT32F, f32, f32;
+        DEPTH24_STENCIL8             => DEPTH24_STENCIL8,   f32, u24u8;
+        DEPTH32F_STENCIL8            => DEPTH32F_STENCIL8,  f32, f32u24u8;
+        STENCIL_INDEX8               => STENCIL_INDEX8,     u32, u8;
+    }
 }
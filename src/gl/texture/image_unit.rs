@@ -0,0 +1,135 @@
+//! Image units through which shaders load from and store into texture images.
+//!
+//! Unlike texture units, image units bind single mipmap level of the texture, with whole layers of layered textures
+//! bound at once. Format of the bound texture must match format layout qualifier of the image variable.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use glsl::image::{memory, FormatQualifier};
+use gl::texture::{self, image, storage, Texture, TextureUnits};
+
+/// Textures whose levels can be bound to image units.
+pub trait ImageTexture {
+    type Target: texture::Target;
+    type InternalFormat: image::marker::Format;
+
+    fn texture_name(&self) -> u32;
+
+    fn texture_levels(&self) -> usize;
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> ImageTexture for Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: texture::Target,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    type Target = T;
+    type InternalFormat = InternalFormat;
+
+    fn texture_name(&self) -> u32 {
+        self.object.name()
+    }
+
+    fn texture_levels(&self) -> usize {
+        self.levels()
+    }
+}
+
+/// `Texture` bound to image unit `BINDING` with `Access`, which is `memory::ReadOnly`, `memory::WriteOnly` or `memory::ReadWrite`.
+#[derive(dm::Deref, dm::DerefMut)]
+pub struct ImageUnit<Texture, Access, const BINDING: usize>
+where
+    Texture: ImageTexture,
+    Access: memory::Access<Base = Access>,
+{
+    #[deref]
+    #[deref_mut]
+    texture: Texture,
+    access: PhantomData<Access>,
+}
+
+impl<Texture, Access, const BINDING: usize> ImageUnit<Texture, Access, BINDING>
+where
+    Texture: ImageTexture,
+    Texture::InternalFormat: FormatQualifier,
+    Access: memory::Access<Base = Access>,
+{
+    /// Bind mipmap `level` of `texture` to image unit `BINDING`, including all layers of layered texture.
+    pub fn new(texture: Texture, level: usize) -> gl::Result<Self> {
+        if level >= texture.texture_levels() {
+            panic!("level {level} is out of bounds of texture with {} levels", texture.texture_levels());
+        }
+        gl::call! {
+            [propagate]
+            unsafe {
+                glb::BindImageTexture(
+                    BINDING as _,
+                    texture.texture_name(),
+                    level as _,
+                    glb::TRUE,
+                    0,
+                    Access::ID,
+                    <Texture::InternalFormat as image::marker::Format>::ID,
+                );
+            }
+        }.map(|_| Self { texture, access: PhantomData })
+    }
+
+    /// Unbind the texture and return it.
+    pub fn into_inner(self) -> Texture {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindImageTexture(BINDING as _, 0, 0, glb::FALSE, 0, glb::READ_ONLY, glb::R8);
+            }
+        }
+        self.texture
+    }
+}
+
+impl<Texture, Access, const BINDING: usize> gl::program::Resource for ImageUnit<Texture, Access, BINDING>
+where
+    Texture: ImageTexture,
+    Texture::InternalFormat: FormatQualifier,
+    Access: memory::Access<Base = Access>,
+{
+    type UniformVariable = glsl::GImage<
+        Texture::Target,
+        <Texture::InternalFormat as image::marker::Format>::Output,
+        Texture::InternalFormat,
+        Access,
+    >;
+
+    fn opaque_uniform_variable<const B: usize>(&self) -> glsl::variable::OpaqueUniformVariable<Self::UniformVariable, B> {
+        glsl::variable::OpaqueUniformVariable::default()
+    }
+}
+
+impl<H, Texture, Access, const BINDING: usize> texture::Binders for (H, &ImageUnit<Texture, Access, BINDING>)
+where
+    H: texture::Binders,
+    Texture: ImageTexture,
+    Access: memory::Access<Base = Access>,
+{
+    type Binders = (H::Binders, ());
+
+    fn binders(&self) -> Self::Binders {
+        (self.0.binders(), ())
+    }
+}
+
+impl<Handles> TextureUnits<Handles>
+where
+    Handles: texture::Binders,
+{
+    pub fn add_image<Texture, Access, const BINDING: usize>(self, unit: &ImageUnit<Texture, Access, BINDING>) -> TextureUnits<(Handles, &ImageUnit<Texture, Access, BINDING>)>
+    where
+        Texture: ImageTexture,
+        Access: memory::Access<Base = Access>,
+    {
+        TextureUnits((self.0, unit))
+    }
+}
@@ -0,0 +1,420 @@
+//! Loading of image files into textures, available with `image` feature.
+//!
+//! PNG, JPEG, BMP and HDR files are decoded and must store exactly the channels and bit depth of requested
+//! internal format, mipmaps are generated from the decoded image. KTX2 files must store the Vulkan format matching
+//! requested internal format, including compressed formats, and provide their own mipmaps if they have any.
+
+use std::borrow::Cow;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::gl;
+use gl::texture::{image, pixel, storage, target, Immutable, Texture};
+use image::format::{components::{R, RG, RGB, RGBA}, F};
+use image::compressed::*;
+use pixel::channels;
+
+/// Texture created by [`load`].
+pub type Loaded<InternalFormat> = Texture<target::D2, Immutable<target::D2>, InternalFormat, true>;
+
+/// Image file given by its path or contents.
+#[derive(Clone, Copy, Debug)]
+pub enum Source<'a> {
+    Path(&'a Path),
+    Bytes(&'a [u8]),
+}
+
+impl<'a> From<&'a Path> for Source<'a> {
+    fn from(path: &'a Path) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl<'a> From<&'a str> for Source<'a> {
+    fn from(path: &'a str) -> Self {
+        Self::Path(Path::new(path))
+    }
+}
+
+impl<'a> From<&'a [u8]> for Source<'a> {
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    #[error("failed to read image file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to decode image: {0}")]
+    Decode(#[from] ::image::ImageError),
+    #[error("image has {found} channels but internal format has {expected} components")]
+    Channels { expected: u16, found: u16 },
+    #[error("image has {found} bits per channel but internal format has {expected} bits per component")]
+    BitDepth { expected: u16, found: u16 },
+    #[error("KTX2 file stores Vulkan format {found} which does not match internal format")]
+    Format { found: u32 },
+    #[error("compressed internal formats can only be loaded from KTX2 files")]
+    Compressed,
+    #[error("unsupported KTX2 file: {0}")]
+    Unsupported(&'static str),
+    #[error("malformed KTX2 file: {0}")]
+    Malformed(&'static str),
+}
+
+/// Internal formats that images can be loaded into.
+pub trait Loadable: image::marker::Storable + Sized {
+    /// Vulkan formats of KTX2 files which store images of the internal format.
+    const VK_FORMATS: &'static [u32];
+
+    /// Bytes of a single texel block, to whose multiples KTX2 files align level data.
+    const BLOCK_BYTES: usize;
+
+    /// Whether mipmaps can be generated from the base level, which `glGenerateMipmap` refuses for compressed formats.
+    const GENERATES_MIPMAPS: bool;
+
+    /// Upload decoded image into base level of `texture`, checking its channels and bit depth.
+    fn upload_decoded(texture: &mut Loaded<Self>, image: ::image::DynamicImage) -> Result<(), Error>;
+
+    /// Upload tightly packed data of given mipmap level.
+    fn upload_level(texture: &mut Loaded<Self>, level: usize, data: &[u8]) -> Result<(), Error>;
+}
+
+/// Components of decoded pixels.
+trait Component: Copy {
+    const SIZE: usize;
+
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_component {
+    ($($ty:ty),+) => {
+        $(
+            impl Component for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    <$ty>::from_le_bytes(bytes.try_into().expect("component has exact size"))
+                }
+            }
+        )+
+    };
+}
+
+impl_component! { u8, u16, f32 }
+
+fn pixels<T: Component, const N: usize>(components: &[T]) -> Vec<[T; N]> {
+    components.as_chunks::<N>().0.to_vec()
+}
+
+fn pixels_from_le_bytes<T: Component, const N: usize>(bytes: &[u8]) -> Vec<[T; N]> {
+    let components: Vec<T> = bytes.chunks_exact(T::SIZE).map(T::from_le_bytes).collect();
+    pixels(&components)
+}
+
+fn check_color<T: Component>(color: ::image::ColorType, components: u16) -> Result<(), Error> {
+    let found = color.channel_count() as u16;
+    if found != components {
+        return Err(Error::Channels { expected: components, found });
+    }
+    let bits = color.bits_per_pixel() / found;
+    let expected = (T::SIZE * 8) as u16;
+    if bits != expected {
+        return Err(Error::BitDepth { expected, found: bits });
+    }
+    Ok(())
+}
+
+macro_rules! impl_loadable {
+    ($format:ty => $channels:ty, [$component:ty; $n:literal], $convert:ident, [$($vk:literal),*]) => {
+        impl Loadable for $format {
+            const VK_FORMATS: &'static [u32] = &[$($vk),*];
+            const BLOCK_BYTES: usize = $n * std::mem::size_of::<$component>();
+            const GENERATES_MIPMAPS: bool = true;
+
+            fn upload_decoded(texture: &mut Loaded<Self>, image: ::image::DynamicImage) -> Result<(), Error> {
+                check_color::<$component>(image.color(), $n)?;
+                let pixels = pixels::<$component, $n>(&image.$convert().into_raw());
+                texture.sub_image_2d_level::<$channels, [$component; $n]>(0, .., .., &pixels);
+                Ok(())
+            }
+
+            fn upload_level(texture: &mut Loaded<Self>, level: usize, data: &[u8]) -> Result<(), Error> {
+                let [width, height] = texture.state.level_dimensions(level);
+                if data.len() != width * height * $n * std::mem::size_of::<$component>() {
+                    return Err(Error::Malformed("level size does not match its dimensions"));
+                }
+                let pixels = pixels_from_le_bytes::<$component, $n>(data);
+                texture.sub_image_2d_level::<$channels, [$component; $n]>(level, .., .., &pixels);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_loadable! { image::Format<R, u8>     => channels::Red,  [u8; 1],  into_luma8,        [9]   }
+impl_loadable! { image::Format<RG, u8>    => channels::RG,   [u8; 2],  into_luma_alpha8,  [16]  }
+impl_loadable! { image::Format<RGB, u8>   => channels::RGB,  [u8; 3],  into_rgb8,         [23]  }
+impl_loadable! { image::Format<RGBA, u8>  => channels::RGBA, [u8; 4],  into_rgba8,        [37]  }
+impl_loadable! { image::format::SRGB8        => channels::RGB,  [u8; 3], into_rgb8,  [29] }
+impl_loadable! { image::format::SRGB8_ALPHA8 => channels::RGBA, [u8; 4], into_rgba8, [43] }
+impl_loadable! { image::Format<R, u16>    => channels::Red,  [u16; 1], into_luma16,       [70]  }
+impl_loadable! { image::Format<RG, u16>   => channels::RG,   [u16; 2], into_luma_alpha16, [77]  }
+impl_loadable! { image::Format<RGB, u16>  => channels::RGB,  [u16; 3], into_rgb16,        [84]  }
+impl_loadable! { image::Format<RGBA, u16> => channels::RGBA, [u16; 4], into_rgba16,       [91]  }
+impl_loadable! { image::Format<RGB, f32, F>  => channels::RGB,  [f32; 3], into_rgb32f,    [106] }
+impl_loadable! { image::Format<RGBA, f32, F> => channels::RGBA, [f32; 4], into_rgba32f,   [109] }
+
+macro_rules! impl_compressed_loadable {
+    ($format:ty, [$($vk:literal),*]) => {
+        impl Loadable for $format {
+            const VK_FORMATS: &'static [u32] = &[$($vk),*];
+            const BLOCK_BYTES: usize = <Self as image::marker::CompressedFormat>::BLOCK_BYTES;
+            const GENERATES_MIPMAPS: bool = false;
+
+            fn upload_decoded(_: &mut Loaded<Self>, _: ::image::DynamicImage) -> Result<(), Error> {
+                Err(Error::Compressed)
+            }
+
+            fn upload_level(texture: &mut Loaded<Self>, level: usize, data: &[u8]) -> Result<(), Error> {
+                let [width, height] = texture.state.level_dimensions(level);
+                if data.len() != <Self as image::marker::CompressedFormat>::image_size(width, height) {
+                    return Err(Error::Malformed("level size does not match its dimensions"));
+                }
+                texture.compressed_sub_image_2d(level, .., .., data);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_compressed_loadable! { COMPRESSED_RGB_S3TC_DXT1, [131] }
+impl_compressed_loadable! { COMPRESSED_SRGB_S3TC_DXT1, [132] }
+impl_compressed_loadable! { COMPRESSED_RGBA_S3TC_DXT1, [133] }
+impl_compressed_loadable! { COMPRESSED_SRGB_ALPHA_S3TC_DXT1, [134] }
+impl_compressed_loadable! { COMPRESSED_RGBA_S3TC_DXT3, [135] }
+impl_compressed_loadable! { COMPRESSED_SRGB_ALPHA_S3TC_DXT3, [136] }
+impl_compressed_loadable! { COMPRESSED_RGBA_S3TC_DXT5, [137] }
+impl_compressed_loadable! { COMPRESSED_SRGB_ALPHA_S3TC_DXT5, [138] }
+impl_compressed_loadable! { COMPRESSED_RED_RGTC1, [139] }
+impl_compressed_loadable! { COMPRESSED_SIGNED_RED_RGTC1, [140] }
+impl_compressed_loadable! { COMPRESSED_RG_RGTC2, [141] }
+impl_compressed_loadable! { COMPRESSED_SIGNED_RG_RGTC2, [142] }
+impl_compressed_loadable! { COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT, [143] }
+impl_compressed_loadable! { COMPRESSED_RGB_BPTC_SIGNED_FLOAT, [144] }
+impl_compressed_loadable! { COMPRESSED_RGBA_BPTC_UNORM, [145] }
+impl_compressed_loadable! { COMPRESSED_SRGB_ALPHA_BPTC_UNORM, [146] }
+impl_compressed_loadable! { COMPRESSED_RGB8_ETC2, [147] }
+impl_compressed_loadable! { COMPRESSED_SRGB8_ETC2, [148] }
+impl_compressed_loadable! { COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2, [149] }
+impl_compressed_loadable! { COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2, [150] }
+impl_compressed_loadable! { COMPRESSED_RGBA8_ETC2_EAC, [151] }
+impl_compressed_loadable! { COMPRESSED_SRGB8_ALPHA8_ETC2_EAC, [152] }
+impl_compressed_loadable! { COMPRESSED_R11_EAC, [153] }
+impl_compressed_loadable! { COMPRESSED_SIGNED_R11_EAC, [154] }
+impl_compressed_loadable! { COMPRESSED_RG11_EAC, [155] }
+impl_compressed_loadable! { COMPRESSED_SIGNED_RG11_EAC, [156] }
+
+const KTX2_IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A];
+const KTX2_LEVEL_INDEX: usize = 80;
+
+/// Load image file into 2D texture of `InternalFormat` with full mipmap chain or mipmaps stored in KTX2 file.
+pub fn load<'a, InternalFormat>(source: impl Into<Source<'a>>) -> Result<Loaded<InternalFormat>, Error>
+where
+    InternalFormat: Loadable,
+{
+    let bytes = match source.into() {
+        Source::Path(path) => Cow::Owned(std::fs::read(path)?),
+        Source::Bytes(bytes) => Cow::Borrowed(bytes),
+    };
+    if bytes.starts_with(&KTX2_IDENTIFIER) {
+        return load_ktx2(&bytes);
+    }
+
+    let image = ::image::load_from_memory(&bytes)?;
+    let [width, height] = [image.width() as usize, image.height() as usize];
+    let levels = storage::max_levels::<target::D2>(&[width, height]);
+    let mut texture = Loaded::<InternalFormat>::create_mipmapped_2d(levels, width, height);
+    InternalFormat::upload_decoded(&mut texture, image)?;
+    texture.generate_mipmaps();
+    Ok(texture)
+}
+
+fn load_ktx2<InternalFormat>(bytes: &[u8]) -> Result<Loaded<InternalFormat>, Error>
+where
+    InternalFormat: Loadable,
+{
+    let ktx2 = parse_ktx2(bytes, InternalFormat::VK_FORMATS, InternalFormat::BLOCK_BYTES, InternalFormat::GENERATES_MIPMAPS)?;
+    let mut texture = Loaded::<InternalFormat>::create_mipmapped_2d(ktx2.levels, ktx2.width, ktx2.height);
+    for (level, data) in ktx2.level_data.iter().enumerate() {
+        InternalFormat::upload_level(&mut texture, level, &bytes[data.clone()])?;
+    }
+    if ktx2.level_data.len() < ktx2.levels {
+        texture.generate_mipmaps();
+    }
+    Ok(texture)
+}
+
+/// Single 2D image stored in KTX2 file.
+#[derive(Debug, PartialEq)]
+struct Ktx2 {
+    width: usize,
+    height: usize,
+    /// Levels of the texture, those past stored ones are generated.
+    levels: usize,
+    /// Byte ranges of stored levels, base level first.
+    level_data: Vec<Range<usize>>,
+}
+
+/// Parse header and level index of KTX2 file storing image of one of `vk_formats` in texel blocks of `block_bytes`.
+///
+/// Files without levels ask for mipmaps to be generated, which is only possible if `generates_mipmaps`.
+fn parse_ktx2(bytes: &[u8], vk_formats: &[u32], block_bytes: usize, generates_mipmaps: bool) -> Result<Ktx2, Error> {
+    let u32_at = |offset: usize| {
+        bytes.get(offset..offset + 4)
+            .map(|field| u32::from_le_bytes(field.try_into().expect("field has 4 bytes")))
+            .ok_or(Error::Malformed("file is truncated"))
+    };
+    let u64_at = |offset: usize| {
+        bytes.get(offset..offset + 8)
+            .map(|field| u64::from_le_bytes(field.try_into().expect("field has 8 bytes")))
+            .ok_or(Error::Malformed("file is truncated"))
+            .and_then(|field| usize::try_from(field).map_err(|_| Error::Malformed("level data is out of bounds")))
+    };
+
+    let vk_format = u32_at(12)?;
+    let [width, height, depth] = [u32_at(20)?, u32_at(24)?, u32_at(28)?].map(|dimension| dimension as usize);
+    let [layers, faces, levels, supercompression] = [u32_at(32)?, u32_at(36)?, u32_at(40)?, u32_at(44)?];
+    if !vk_formats.contains(&vk_format) {
+        return Err(Error::Format { found: vk_format });
+    }
+    if supercompression != 0 {
+        return Err(Error::Unsupported("supercompressed images"));
+    }
+    if width == 0 || height == 0 || depth != 0 || layers != 0 || faces != 1 {
+        return Err(Error::Unsupported("only single 2D images can be loaded"));
+    }
+    if levels == 0 && !generates_mipmaps {
+        return Err(Error::Unsupported("mipmaps of compressed images cannot be generated"));
+    }
+
+    let max_levels = storage::max_levels::<target::D2>(&[width, height]);
+    let stored_levels = (levels as usize).max(1);
+    if stored_levels > max_levels {
+        return Err(Error::Malformed("more levels than dimensions allow"));
+    }
+    // Levels are preceded by mip padding which aligns them to both texel blocks and 4 bytes.
+    let alignment = lcm(block_bytes, 4);
+    let level_data = (0..stored_levels)
+        .map(|level| {
+            let entry = KTX2_LEVEL_INDEX + level * 24;
+            let (offset, length) = (u64_at(entry)?, u64_at(entry + 8)?);
+            if !offset.is_multiple_of(alignment) {
+                return Err(Error::Malformed("level data is not aligned to mip padding"));
+            }
+            let end = offset.checked_add(length).ok_or(Error::Malformed("level data is out of bounds"))?;
+            if end > bytes.len() {
+                return Err(Error::Malformed("level data is out of bounds"));
+            }
+            Ok(offset..end)
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Ktx2 {
+        width,
+        height,
+        levels: if levels == 0 { max_levels } else { stored_levels },
+        level_data,
+    })
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let [mut x, mut y] = [a, b];
+    while y != 0 {
+        [x, y] = [y, x % y];
+    }
+    a / x * b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RGBA8: u32 = 37;
+    const RGB8: u32 = 23;
+    const BC1: u32 = 131;
+
+    /// KTX2 file of single 2D image with given level index, whose level data is zeroed.
+    fn file(vk_format: u32, [width, height]: [u32; 2], levels: u32, index: &[(u64, u64)], len: usize) -> Vec<u8> {
+        let mut bytes = vec![0; len];
+        bytes[..12].copy_from_slice(&KTX2_IDENTIFIER);
+        for (offset, value) in [(12, vk_format), (20, width), (24, height), (36, 1), (40, levels)] {
+            bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        for (level, (offset, length)) in index.iter().enumerate() {
+            let entry = KTX2_LEVEL_INDEX + level * 24;
+            bytes[entry..entry + 8].copy_from_slice(&offset.to_le_bytes());
+            bytes[entry + 8..entry + 16].copy_from_slice(&length.to_le_bytes());
+            bytes[entry + 16..entry + 24].copy_from_slice(&length.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn single_level_is_parsed() {
+        let bytes = file(RGBA8, [4, 4], 1, &[(104, 64)], 168);
+        let ktx2 = parse_ktx2(&bytes, &[RGBA8], 4, true).unwrap();
+        assert_eq!(ktx2, Ktx2 { width: 4, height: 4, levels: 1, level_data: vec![104..168] });
+    }
+
+    #[test]
+    fn missing_levels_are_left_for_generation() {
+        let bytes = file(RGBA8, [4, 4], 0, &[(104, 64)], 168);
+        let ktx2 = parse_ktx2(&bytes, &[RGBA8], 4, true).unwrap();
+        assert_eq!(ktx2.levels, 3);
+        assert_eq!(ktx2.level_data, vec![104..168]);
+    }
+
+    #[test]
+    fn missing_levels_of_compressed_image_are_unsupported() {
+        let bytes = file(BC1, [4, 4], 0, &[(104, 8)], 112);
+        assert!(matches!(parse_ktx2(&bytes, &[BC1], 8, false), Err(Error::Unsupported(_))));
+
+        let bytes = file(BC1, [4, 4], 1, &[(104, 8)], 112);
+        let ktx2 = parse_ktx2(&bytes, &[BC1], 8, false).unwrap();
+        assert_eq!(ktx2, Ktx2 { width: 4, height: 4, levels: 1, level_data: vec![104..112] });
+    }
+
+    #[test]
+    fn levels_are_read_past_mip_padding() {
+        // 3 byte texels are aligned to 12 bytes, smallest level is stored first.
+        let bytes = file(RGB8, [2, 2], 2, &[(144, 12), (132, 3)], 156);
+        let ktx2 = parse_ktx2(&bytes, &[RGB8], 3, true).unwrap();
+        assert_eq!(ktx2.level_data, vec![144..156, 132..135]);
+
+        let bytes = file(RGB8, [2, 2], 2, &[(140, 12), (128, 3)], 156);
+        assert!(matches!(parse_ktx2(&bytes, &[RGB8], 3, true), Err(Error::Malformed(_))));
+    }
+
+    #[test]
+    fn overflowing_level_is_malformed() {
+        let bytes = file(RGBA8, [4, 4], 1, &[(u64::MAX - 3, 64)], 168);
+        assert!(matches!(parse_ktx2(&bytes, &[RGBA8], 4, true), Err(Error::Malformed(_))));
+
+        let bytes = file(RGBA8, [4, 4], 1, &[(104, 65)], 168);
+        assert!(matches!(parse_ktx2(&bytes, &[RGBA8], 4, true), Err(Error::Malformed(_))));
+    }
+
+    #[test]
+    fn invalid_headers_are_rejected() {
+        let bytes = file(RGBA8, [4, 4], 1, &[(104, 64)], 168);
+        assert!(matches!(parse_ktx2(&bytes[..90], &[RGBA8], 4, true), Err(Error::Malformed(_))));
+        assert!(matches!(parse_ktx2(&bytes, &[RGB8], 3, true), Err(Error::Format { found: RGBA8 })));
+
+        let bytes = file(RGBA8, [4, 4], 4, &[(104, 64)], 168);
+        assert!(matches!(parse_ktx2(&bytes, &[RGBA8], 4, true), Err(Error::Malformed(_))));
+
+        let bytes = file(RGBA8, [4, 0], 1, &[(104, 64)], 168);
+        assert!(matches!(parse_ktx2(&bytes, &[RGBA8], 4, true), Err(Error::Unsupported(_))));
+    }
+}
@@ -3,6 +3,15 @@ pub mod storage;
 pub mod valid;
 pub mod pixel;
 pub mod image;
+pub mod filter;
+pub mod parameter;
+pub mod sampler;
+pub mod image_unit;
+pub mod stream;
+pub mod copy;
+pub mod unit;
+#[cfg(feature = "image")]
+pub mod load;
 
 use std::ops::Deref;
 
@@ -21,6 +30,12 @@ use crate::prelude::internal::*;
 use crate::gl::object::*;
 pub use target::{Target, Buffer};
 pub use storage::{Immutable, Mutable, Storage};
+pub use sampler::Sampler;
+pub use image_unit::ImageUnit;
+pub use stream::UnpackStream;
+pub use unit::TextureUnitAllocator;
+#[cfg(feature = "image")]
+pub use load::load;
 
 #[hi::mark(PartialObject, Object)]
 pub struct TextureObject<T>(PhantomData<T>) where T: Target;
@@ -63,7 +78,7 @@ where
 }
 
 #[derive(dm::Deref)]
-pub struct TextureState<T, K, F>
+pub struct TextureState<T, K, F, const MIPMAPS: bool = false>
 where
     T: texture::Target,
     K: storage::marker::Kind<Target = T>,
@@ -71,21 +86,25 @@ where
 {
     target: PhantomData<T>,
     #[deref]
-    storage: storage::Storage<T, K, F, false>,
+    storage: storage::Storage<T, K, F, MIPMAPS>,
 }
 
-impl<T, K, F> TextureState<T, K, F>
+impl<T, K, F, const MIPMAPS: bool> TextureState<T, K, F, MIPMAPS>
 where
     T: texture::Target,
     K: storage::marker::Kind<Target = T>,
     F: image::marker::Format,
 {
-    const fn new(storage: Storage::<T, K, F, false>) -> Self {
+    const fn new(storage: Storage::<T, K, F, MIPMAPS>) -> Self {
         Self {
             storage,
             target: PhantomData,
         }
     }
+
+    fn cast<G: image::marker::Format>(self) -> TextureState<T, K, G, MIPMAPS> {
+        TextureState::new(self.storage.cast())
+    }
 }
 
 pub trait MaybeFormat: ts::Maybe + texture::image::marker::Format { }
@@ -98,6 +117,7 @@ impl texture::image::marker::Format for ts::None {
     
     type Composition = texture::image::marker::Aggregate;
     type ComponentType = u8;
+    type Sampling = texture::image::marker::Plain;
     // type Kind = gl::types::Float;
 }
 
@@ -109,6 +129,7 @@ impl<F> texture::image::marker::Format for ts::Some<F> where F: texture::image::
     
     type Composition = F::Composition;
     type ComponentType = F::ComponentType;
+    type Sampling = F::Sampling;
 }
 
 pub struct Builder<T, K, F>
@@ -141,8 +162,11 @@ where
     }
 }
 
+/// Texture object together with its storage.
+///
+/// `MIPMAPS` tells whether storage may contain more than the base level.
 #[derive(dm::Deref)]
-pub struct Texture<T, K, InterFormat>
+pub struct Texture<T, K, InterFormat, const MIPMAPS: bool = false>
 where
     T: Target,
     K: storage::marker::Kind<Target = T>,
@@ -150,38 +174,296 @@ where
 {
     #[deref]
     object: ObjectBase<TextureObject<T>>,
-    state: TextureState<T, K, InterFormat>
+    state: TextureState<T, K, InterFormat, MIPMAPS>
 }
 
 
 use image::marker::BaseFormat;
 
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Target,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Dimensions of the base level of the texture image.
+    pub fn dimensions(&self) -> &T::Dimensions {
+        self.state.dimensions()
+    }
+
+    /// Number of mipmap levels of the texture image.
+    pub fn levels(&self) -> usize {
+        self.state.levels()
+    }
+
+    /// Dimensions of given mipmap level extended to 3 axes. Faces of cube map count as its layers.
+    fn level_extent(&self, level: usize) -> [usize; 3] {
+        let dimensions = self.state.level_dimensions(level);
+        let dimensions = dimensions.as_ref();
+        let [width, height, depth] = [0, 1, 2].map(|axis| dimensions.get(axis).copied().unwrap_or(1));
+        if T::ID == glb::TEXTURE_CUBE_MAP {
+            [width, height, target::CubeFace::ALL.len()]
+        } else {
+            [width, height, depth]
+        }
+    }
+}
+
+/// Whether last axis of target `id` indexes array layers.
+fn is_layered(id: u32) -> bool {
+    matches!(
+        id,
+        glb::TEXTURE_1D_ARRAY | glb::TEXTURE_2D_ARRAY | glb::TEXTURE_CUBE_MAP_ARRAY | glb::TEXTURE_2D_MULTISAMPLE_ARRAY
+    )
+}
+
+impl<T, InternalFormat, const MIPMAPS: bool> Texture<T, Immutable<T>, InternalFormat, MIPMAPS>
+where
+    T: Target + storage::marker::Internal,
+    InternalFormat: image::marker::ViewClass,
+{
+    /// Number of layers of the texture, where faces of cube map count as layers.
+    fn layers(&self) -> usize {
+        if T::ID == glb::TEXTURE_CUBE_MAP {
+            target::CubeFace::ALL.len()
+        } else if is_layered(T::ID) {
+            self.dimensions().as_ref().last().copied().unwrap_or(1)
+        } else {
+            1
+        }
+    }
+
+    /// Create texture sharing `levels` and `layers` of this texture's storage, reinterpreted as `NewTarget` texture
+    /// of internal format `NewFormat`.
+    ///
+    /// Only immutable storage can be viewed and `NewFormat` must be in the same view class as the texture's format.
+    /// Cube map views take exactly 6 layers and cube map array views a multiple of 6.
+    pub fn view<NewTarget, NewFormat>(
+        &self,
+        levels: std::ops::Range<usize>,
+        layers: std::ops::Range<usize>,
+    ) -> Texture<NewTarget, Immutable<NewTarget>, NewFormat, MIPMAPS>
+    where
+        NewTarget: Target + storage::marker::Internal + valid::ForView<T>,
+        NewFormat: image::marker::ViewClass<Class = InternalFormat::Class> + image::marker::Storable,
+    {
+        if levels.is_empty() || levels.end > self.levels() {
+            panic!("view levels {levels:?} are out of bounds of texture with {} levels", self.levels());
+        }
+        if layers.is_empty() || layers.end > self.layers() {
+            panic!("view layers {layers:?} are out of bounds of texture with {} layers", self.layers());
+        }
+        let expected = match NewTarget::ID {
+            glb::TEXTURE_CUBE_MAP => layers.len() == target::CubeFace::ALL.len(),
+            glb::TEXTURE_CUBE_MAP_ARRAY => layers.len().is_multiple_of(target::CubeFace::ALL.len()),
+            id => is_layered(id) || layers.len() == 1,
+        };
+        if !expected {
+            panic!("{} layers cannot form view of target {:#x}", layers.len(), NewTarget::ID);
+        }
+
+        let extent = self.level_extent(levels.start);
+        let mut dimensions = NewTarget::Dimensions::default();
+        let axes = dimensions.as_mut();
+        let last = axes.len() - 1;
+        axes.copy_from_slice(&extent[..axes.len()]);
+        if is_layered(NewTarget::ID) {
+            axes[last] = layers.len();
+        }
+        let storage = Storage::view(dimensions, levels.len());
+
+        let mut name = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GenTextures(1, &mut name);
+                glb::TextureView(
+                    name,
+                    NewTarget::ID,
+                    self.name(),
+                    NewFormat::ID,
+                    levels.start as _,
+                    levels.len() as _,
+                    layers.start as _,
+                    layers.len() as _,
+                );
+            }
+        }
+        // SAFETY: name was just generated for texture object and is not owned elsewhere.
+        let object = unsafe { ObjectBase::from_name(name) };
+        if !MIPMAPS {
+            parameter::without_mipmaps(name);
+        }
+        Texture { object, state: TextureState::new(storage) }
+    }
+}
+
+impl<T, K, InternalFormat> Texture<T, K, InternalFormat, true>
+where
+    T: Target + valid::ForMipmaps,
+    K: storage::marker::Storage<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Fill all levels below the base level by downsampling it.
+    pub fn generate_mipmaps(&mut self) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GenerateTextureMipmap(self.name());
+            }
+        }
+    }
+
+    /// Set the lowest mipmap level accessible when sampling.
+    pub fn base_level(&mut self, level: usize) {
+        if level >= self.levels() {
+            panic!("base level {level} is out of bounds of texture with {} levels", self.levels());
+        }
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameteri(self.name(), glb::TEXTURE_BASE_LEVEL, level as _);
+            }
+        }
+    }
+
+    /// Set the highest mipmap level accessible when sampling.
+    pub fn max_level(&mut self, level: usize) {
+        if level >= self.levels() {
+            panic!("max level {level} is out of bounds of texture with {} levels", self.levels());
+        }
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameteri(self.name(), glb::TEXTURE_MAX_LEVEL, level as _);
+            }
+        }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Target + valid::ForPixelTransfer,
+    K: storage::marker::Storage<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Read back a box of pixels from given mipmap level.
+    ///
+    /// Axes which texture does not have must be given range `0..1`.
+    pub fn get_sub_image<Channels, Pixel>(
+        &self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        z_range: impl std::ops::RangeBounds<usize>,
+    ) -> Vec<Pixel>
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let [width, height, depth] = self.level_extent(level);
+        let (x_start, x_end) = storage::range("width", width, x_range);
+        let (y_start, y_end) = storage::range("height", height, y_range);
+        let (z_start, z_end) = storage::range("depth", depth, z_range);
+
+        let x_length = x_end - x_start;
+        let y_length = y_end - y_start;
+        let z_length = z_end - z_start;
+        let length = x_length * y_length * z_length;
+
+        let mut pixels = pixel::zeroed::<Pixel>(length);
+        pixel::pack_rows::<Pixel>(x_length);
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetTextureSubImage(
+                    self.name(),
+                    level as _,
+                    x_start as _,
+                    y_start as _,
+                    z_start as _,
+                    x_length as _,
+                    y_length as _,
+                    z_length as _,
+                    <(Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind) as pixel::FormatToken>::ID,
+                    Pixel::type_token(),
+                    (length * std::mem::size_of::<Pixel>()) as _,
+                    pixels.as_mut_ptr() as *mut _,
+                );
+            }
+        }
+        pixels
+    }
+}
+
 
 impl<D1Target, Kind, InternalFormat> Texture<D1Target, Kind, InternalFormat>
 where
     D1Target: texture::Target<Dimensions = [usize; 1]>,
     Kind: storage::marker::Storage<Target=D1Target, Signature = storage::signature::Storage1D>,
-    InternalFormat: image::marker::Format,
+    InternalFormat: image::marker::Storable,
 {
     pub fn create_with_storage_1d(width: usize) -> Self {
         let mut object = ObjectBase::default();
         let binder = object.bind();
 
-        let storage = Storage::storage_1d(&binder, width);
+        let storage = Storage::storage_1d(&binder, 1, width);
+        parameter::without_mipmaps(object.name());
         Self { object, state: TextureState::new(storage) }
     }
+}
+
+impl<D1Target, Kind, InternalFormat> Texture<D1Target, Kind, InternalFormat, true>
+where
+    D1Target: texture::Target<Dimensions = [usize; 1]> + valid::ForMipmaps,
+    Kind: storage::marker::Storage<Target=D1Target, Signature = storage::signature::Storage1D>,
+    InternalFormat: image::marker::Storable,
+{
+    /// Create texture with storage for `levels` mipmap levels.
+    pub fn create_mipmapped_1d(levels: usize, width: usize) -> Self {
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
 
+        let storage = Storage::storage_1d(&binder, levels, width);
+        Self { object, state: TextureState::new(storage) }
+    }
+}
+
+impl<D1Target, Kind, InternalFormat, const MIPMAPS: bool> Texture<D1Target, Kind, InternalFormat, MIPMAPS>
+where
+    D1Target: texture::Target<Dimensions = [usize; 1]>,
+    Kind: storage::marker::Storage<Target=D1Target, Signature = storage::signature::Storage1D>,
+    InternalFormat: image::marker::Format,
+{
     pub fn sub_image_1d<Channels: pixel::channels::Channels>(
         &mut self, 
         x_range: impl std::ops::RangeBounds<usize>, 
-        pixels: &[impl pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>]
+        pixels: &[impl pixel::valid::ForChannels<Channels, Components = Channels::Components, Type = InternalFormat::ComponentType>]
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_1d_level::<Channels>(0, x_range, pixels)
+    }
+
+    /// Write pixels into given mipmap level. Ranges are relative to dimensions of the level.
+    pub fn sub_image_1d_level<Channels>(
+        &mut self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[impl pixel::valid::ForChannels<Channels, Components = Channels::Components, Type = InternalFormat::ComponentType>]
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
         let binder = self.bind();
-        self.state.storage.sub_image_1d(&binder, x_range, pixels)
+        self.state.storage.sub_image_1d::<Channels, _>(&binder, level, x_range, pixels)
     }
 }
 
@@ -189,22 +471,51 @@ impl<D2Target, Kind, InternalFormat> Texture<D2Target, Kind, InternalFormat>
 where
     D2Target: texture::Target<Dimensions = [usize; 2]>,
     Kind: storage::marker::Storage<Target=D2Target, Signature = storage::signature::Storage2D>,
-    InternalFormat: image::marker::Format,
+    InternalFormat: image::marker::Storable,
 {
     pub fn create_with_storage_2d(width: usize, height: usize) -> Self {
         let mut object = ObjectBase::default();
         let binder = object.bind();
 
-        let storage = Storage::storage_2d(&binder, width, height);
+        let storage = Storage::storage_2d(&binder, 1, width, height);
+        parameter::without_mipmaps(object.name());
         Self {
             object,
             state: TextureState::new(storage),
         }
     }
+}
+
+impl<D2Target, Kind, InternalFormat> Texture<D2Target, Kind, InternalFormat, true>
+where
+    D2Target: texture::Target<Dimensions = [usize; 2]> + valid::ForMipmaps,
+    Kind: storage::marker::Storage<Target=D2Target, Signature = storage::signature::Storage2D>,
+    InternalFormat: image::marker::Storable,
+{
+    /// Create texture with storage for `levels` mipmap levels.
+    ///
+    /// Full mipmap chain has [`storage::max_levels`] levels.
+    pub fn create_mipmapped_2d(levels: usize, width: usize, height: usize) -> Self {
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
 
+        let storage = Storage::storage_2d(&binder, levels, width, height);
+        Self {
+            object,
+            state: TextureState::new(storage),
+        }
+    }
+}
+
+impl<D2Target, Kind, InternalFormat, const MIPMAPS: bool> Texture<D2Target, Kind, InternalFormat, MIPMAPS>
+where
+    D2Target: texture::Target<Dimensions = [usize; 2]> + valid::ForSubImage2D,
+    Kind: storage::marker::Storage<Target=D2Target, Signature = storage::signature::Storage2D>,
+    InternalFormat: image::marker::Format,
+{
     pub fn sub_image_2d<
         Channels: pixel::channels::Channels,
-        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
     >(
         &mut self,
         x_range: impl std::ops::RangeBounds<usize>, 
@@ -213,10 +524,69 @@ where
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_2d_level::<Channels, Pixel>(0, x_range, y_range, pixels);
+    }
+
+    /// Write pixels into given mipmap level. Ranges are relative to dimensions of the level.
+    pub fn sub_image_2d_level<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel]
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
         let binder = self.bind();
-        self.state.storage.sub_image_2d(&binder, x_range, y_range, pixels);
+        self.state.storage.sub_image_2d::<Channels, Pixel>(&binder, level, x_range, y_range, pixels);
+    }
+
+    /// Write pixels sourced from pixel unpack `buffer`, starting at pixel `offset`, into base level.
+    ///
+    /// Upload is queued without waiting for the buffer contents, which lets large uploads overlap with rendering.
+    pub fn sub_image_2d_from_buffer<Channels, Pixel>(
+        &mut self,
+        buffer: &gl::Buffer<buffer::PixelUnpack, Pixel>,
+        offset: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_2d_level_from_buffer::<Channels, Pixel>(0, buffer, offset, x_range, y_range);
+    }
+
+    /// Write pixels sourced from pixel unpack `buffer` into given mipmap level.
+    pub fn sub_image_2d_level_from_buffer<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        buffer: &gl::Buffer<buffer::PixelUnpack, Pixel>,
+        offset: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let binder = self.bind();
+        self.state.storage.sub_image_2d_from_buffer::<Channels, Pixel>(&binder, level, buffer, offset, x_range, y_range);
     }
 }
 
@@ -224,22 +594,51 @@ impl<D3Target, Kind, InternalFormat> Texture<D3Target, Kind, InternalFormat>
 where
     D3Target: texture::Target<Dimensions = [usize; 3]>,
     Kind: storage::marker::Storage<Target=D3Target, Signature = storage::signature::Storage3D>,
-    InternalFormat: image::marker::Format,
+    InternalFormat: image::marker::Storable,
 {
     pub fn create_with_storage_3d(_: &object::Bind<TextureObject<D3Target>>, width: usize, height: usize, depth: usize) -> Self {
         let mut object = ObjectBase::default();
         let binder = object.bind();
 
-        let storage = Storage::storage_3d(&binder, width, height, depth);
+        let storage = Storage::storage_3d(&binder, 1, width, height, depth);
+        parameter::without_mipmaps(object.name());
+
+        Self {
+            object,
+            state: TextureState::new(storage),
+        }
+    }
+}
+
+impl<D3Target, Kind, InternalFormat> Texture<D3Target, Kind, InternalFormat, true>
+where
+    D3Target: texture::Target<Dimensions = [usize; 3]> + valid::ForMipmaps,
+    Kind: storage::marker::Storage<Target=D3Target, Signature = storage::signature::Storage3D>,
+    InternalFormat: image::marker::Storable,
+{
+    /// Create texture with storage for `levels` mipmap levels. Array layers are not reduced between levels.
+    pub fn create_mipmapped_3d(levels: usize, width: usize, height: usize, depth: usize) -> Self {
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
+
+        let storage = Storage::storage_3d(&binder, levels, width, height, depth);
 
         Self {
             object,
             state: TextureState::new(storage),
         }
     }
+}
+
+impl<D3Target, Kind, InternalFormat, const MIPMAPS: bool> Texture<D3Target, Kind, InternalFormat, MIPMAPS>
+where
+    D3Target: texture::Target<Dimensions = [usize; 3]> + valid::ForPixelTransfer,
+    Kind: storage::marker::Storage<Target=D3Target, Signature = storage::signature::Storage3D>,
+    InternalFormat: image::marker::Format,
+{
     pub fn sub_image_3d<
         Channels: pixel::channels::Channels,
-        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
     >(
         &mut self, 
         x_range: impl std::ops::RangeBounds<usize>,
@@ -249,28 +648,479 @@ where
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
+        self.sub_image_3d_level::<Channels, Pixel>(0, x_range, y_range, z_range, pixels);
+    }
+
+    /// Write pixels into given mipmap level. Ranges are relative to dimensions of the level.
+    pub fn sub_image_3d_level<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        z_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel]
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let binder = self.bind();
+        self.state.storage.sub_image_3d::<Channels, Pixel>(&binder, level, x_range, y_range, z_range, pixels);
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Target + valid::ForPixelTransfer,
+    K: storage::marker::Storage<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Write pixels into single layer of given mipmap level.
+    fn sub_image_layer<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        layer: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel],
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let [width, height, depth] = self.level_extent(level);
+        let (x_start, x_end) = storage::range("width", width, x_range);
+        let (y_start, y_end) = storage::range("height", height, y_range);
+        if layer >= depth {
+            panic!("layer {layer} is out of bounds of {depth} layers");
+        }
+
+        let x_length = x_end - x_start;
+        let y_length = y_end - y_start;
+        storage::check_pixels(pixels.len(), [x_length, y_length]);
+
+        pixel::unpack_rows::<Pixel>(x_length);
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureSubImage3D(
+                    self.name(),
+                    level as _,
+                    x_start as _,
+                    y_start as _,
+                    layer as _,
+                    x_length as _,
+                    y_length as _,
+                    1,
+                    <(Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind) as pixel::FormatToken>::ID,
+                    Pixel::type_token(),
+                    pixels.as_ptr() as *const _,
+                );
+            }
+        }
+    }
+}
+
+impl<Kind, InternalFormat> Texture<target::CubeMap, Kind, InternalFormat>
+where
+    Kind: storage::marker::Storage<Target=target::CubeMap, Signature = storage::signature::Storage2D>,
+    InternalFormat: image::marker::Storable,
+{
+    /// Create cube map with faces of `size`x`size` texels filled from images given in [`target::CubeFace::ALL`] order.
+    pub fn from_faces<Channels, Pixel>(size: usize, faces: [&[Pixel]; 6]) -> Self
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let mut texture = Self::create_with_storage_2d(size, size);
+        for (face, pixels) in target::CubeFace::ALL.into_iter().zip(faces) {
+            texture.sub_image_face::<Channels, Pixel>(face, .., .., pixels);
+        }
+        texture
+    }
+}
+
+impl<Kind, InternalFormat, const MIPMAPS: bool> Texture<target::CubeMap, Kind, InternalFormat, MIPMAPS>
+where
+    Kind: storage::marker::Storage<Target=target::CubeMap>,
+    InternalFormat: image::marker::Format,
+{
+    pub fn sub_image_face<Channels, Pixel>(
+        &mut self,
+        face: target::CubeFace,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel],
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_face_level::<Channels, Pixel>(0, face, x_range, y_range, pixels);
+    }
+
+    /// Write pixels into face of given mipmap level. Ranges are relative to dimensions of the level.
+    pub fn sub_image_face_level<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        face: target::CubeFace,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel],
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_layer::<Channels, Pixel>(level, face.layer(), x_range, y_range, pixels);
+    }
+}
+
+impl<Kind, InternalFormat, const MIPMAPS: bool> Texture<target::CubeMapArray, Kind, InternalFormat, MIPMAPS>
+where
+    Kind: storage::marker::Storage<Target=target::CubeMapArray>,
+    InternalFormat: image::marker::Format,
+{
+    /// Number of cube maps in the array.
+    pub fn cubes(&self) -> usize {
+        self.dimensions()[2] / target::CubeFace::ALL.len()
+    }
+
+    pub fn sub_image_layer_face<Channels, Pixel>(
+        &mut self,
+        layer: usize,
+        face: target::CubeFace,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel],
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        self.sub_image_layer_face_level::<Channels, Pixel>(0, layer, face, x_range, y_range, pixels);
+    }
+
+    /// Write pixels into face of cube `layer` at given mipmap level.
+    pub fn sub_image_layer_face_level<Channels, Pixel>(
+        &mut self,
+        level: usize,
+        layer: usize,
+        face: target::CubeFace,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        pixels: &[Pixel],
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        if layer >= self.cubes() {
+            panic!("cube layer {layer} is out of bounds of array with {} cubes", self.cubes());
+        }
+        let layer_face = layer * target::CubeFace::ALL.len() + face.layer();
+        self.sub_image_layer::<Channels, Pixel>(level, layer_face, x_range, y_range, pixels);
+    }
+}
+
+/// Resolve range along axis of compressed image into `(start, length)`, checking it is aligned to blocks of `block` texels.
+///
+/// Range may end at the edge of the image even if it is not a multiple of the block size.
+fn block_range(axis: &str, len: usize, block: usize, span: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
+    let (start, end) = storage::range(axis, len, span);
+    if start % block != 0 || (end % block != 0 && end != len) {
+        panic!("sub image {axis} range {start}..{end} is not aligned to blocks of {block} texels");
+    }
+    (start, end - start)
+}
+
+fn check_compressed_len<InternalFormat: image::marker::CompressedFormat>(width: usize, height: usize, depth: usize, len: usize) {
+    let expected = InternalFormat::image_size(width, height) * depth;
+    if len != expected {
+        panic!("compressed data of {len} bytes does not match {expected} bytes of {width}x{height}x{depth} region");
+    }
+}
+
+impl<D2Target, Kind, InternalFormat, const MIPMAPS: bool> Texture<D2Target, Kind, InternalFormat, MIPMAPS>
+where
+    D2Target: texture::Target<Dimensions = [usize; 2]> + valid::ForSubImage2D,
+    Kind: storage::marker::Storage<Target=D2Target>,
+    InternalFormat: image::marker::CompressedFormat,
+{
+    /// Write pre-compressed blocks into region of given mipmap level.
+    ///
+    /// Region must start at block boundary and end at block boundary or edge of the level.
+    pub fn compressed_sub_image_2d(
+        &mut self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        data: &[u8],
+    ) {
+        let [width, height, _] = self.level_extent(level);
+        let (x_start, x_length) = block_range("width", width, InternalFormat::BLOCK_WIDTH, x_range);
+        let (y_start, y_length) = block_range("height", height, InternalFormat::BLOCK_HEIGHT, y_range);
+        check_compressed_len::<InternalFormat>(x_length, y_length, 1, data.len());
+
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::CompressedTextureSubImage2D(
+                    self.name(),
+                    level as _,
+                    x_start as _,
+                    y_start as _,
+                    x_length as _,
+                    y_length as _,
+                    InternalFormat::ID,
+                    data.len() as _,
+                    data.as_ptr() as *const _,
+                );
+            }
+        }
+    }
+}
+
+impl<D3Target, Kind, InternalFormat, const MIPMAPS: bool> Texture<D3Target, Kind, InternalFormat, MIPMAPS>
+where
+    D3Target: texture::Target<Dimensions = [usize; 3]> + valid::ForPixelTransfer,
+    Kind: storage::marker::Storage<Target=D3Target>,
+    InternalFormat: image::marker::CompressedFormat,
+{
+    /// Write pre-compressed blocks into region of given mipmap level, one block image per layer or slice.
+    ///
+    /// Region must start at block boundary and end at block boundary or edge of the level.
+    pub fn compressed_sub_image_3d(
+        &mut self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+        z_range: impl std::ops::RangeBounds<usize>,
+        data: &[u8],
+    ) {
+        let [width, height, depth] = self.level_extent(level);
+        let (x_start, x_length) = block_range("width", width, InternalFormat::BLOCK_WIDTH, x_range);
+        let (y_start, y_length) = block_range("height", height, InternalFormat::BLOCK_HEIGHT, y_range);
+        let (z_start, z_length) = block_range("depth", depth, 1, z_range);
+        check_compressed_len::<InternalFormat>(x_length, y_length, z_length, data.len());
+
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::CompressedTextureSubImage3D(
+                    self.name(),
+                    level as _,
+                    x_start as _,
+                    y_start as _,
+                    z_start as _,
+                    x_length as _,
+                    y_length as _,
+                    z_length as _,
+                    InternalFormat::ID,
+                    data.len() as _,
+                    data.as_ptr() as *const _,
+                );
+            }
+        }
+    }
+}
+
+/// Kinds of stored values paired with limit of samples of multisample textures storing them.
+pub trait MaxSamples {
+    const ID: u32;
+}
+
+gl::impl_token! { gl::types::Float   as MaxSamples => MAX_COLOR_TEXTURE_SAMPLES }
+gl::impl_token! { gl::types::Integer as MaxSamples => MAX_INTEGER_SAMPLES       }
+
+/// Check `samples` against limit for multisample textures of given internal format.
+fn check_samples<InternalFormat>(samples: usize)
+where
+    InternalFormat: image::marker::Format,
+    <InternalFormat::Output as glsl::sampler::Output>::Kind: MaxSamples,
+{
+    let depth_stencil = matches!(InternalFormat::BaseFormat::ID, glb::DEPTH_COMPONENT | glb::DEPTH_STENCIL | glb::STENCIL_INDEX);
+    let limit_id = if depth_stencil {
+        glb::MAX_DEPTH_TEXTURE_SAMPLES
+    } else {
+        <<InternalFormat::Output as glsl::sampler::Output>::Kind as MaxSamples>::ID
+    };
+    let mut limit = 0;
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::GetIntegerv(limit_id, &mut limit);
+        }
+    }
+    if samples == 0 || samples > limit as usize {
+        panic!("sample count {samples} is outside of 1..={limit} supported for the internal format");
+    }
+}
+
+impl<T, InternalFormat, const MIPMAPS: bool> Texture<T, Mutable<T>, InternalFormat, MIPMAPS>
+where
+    T: Target + storage::marker::Internal,
+    Mutable<T>: storage::Specification<Target = T>,
+    InternalFormat: image::marker::Format,
+    (InternalFormat::BaseFormat, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+{
+    /// Create texture with mutable storage for `levels` mipmap levels, which can be resized with `reallocate`.
+    ///
+    /// Textures without mipmaps must be given exactly 1 level.
+    pub fn create_mutable(levels: usize, dimensions: T::Dimensions) -> Self
+    where
+        InternalFormat: image::marker::Storable,
+    {
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
+
+        let storage = Storage::image(&binder, levels, dimensions);
+        if !MIPMAPS {
+            parameter::without_mipmaps(object.name());
+        }
+        Self { object, state: TextureState::new(storage) }
+    }
+
+    /// Re-specify all levels of the storage for base level of new `dimensions`, discarding their contents.
+    ///
+    /// Level count is kept unless it exceeds the full mipmap chain of new dimensions.
+    /// Max level set with [`max_level`](Self::max_level) is kept as well, clamped to the last remaining level.
+    pub fn reallocate(&mut self, dimensions: T::Dimensions) {
         let binder = self.bind();
-        self.state.storage.sub_image_3d(&binder, x_range, y_range, z_range, pixels);
+        self.state.storage.reallocate(&binder, dimensions);
+    }
+}
+
+impl<Kind, InternalFormat> Texture<target::D2MultiSample, Kind, InternalFormat>
+where
+    Kind: storage::marker::Storage<Target=target::D2MultiSample, Signature = storage::signature::Storage2DMultisample>,
+    InternalFormat: image::marker::Storable,
+    <InternalFormat::Output as glsl::sampler::Output>::Kind: MaxSamples,
+{
+    /// Create multisample texture with given number of `samples` per texel.
+    ///
+    /// With `fixed_sample_locations` samples are at the same positions in every texel.
+    pub fn create_multisample_2d(samples: usize, width: usize, height: usize, fixed_sample_locations: bool) -> Self {
+        check_samples::<InternalFormat>(samples);
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
+
+        let storage = Storage::storage_2d_multisample(&binder, samples, width, height, fixed_sample_locations);
+        Self {
+            object,
+            state: TextureState::new(storage),
+        }
+    }
+}
+
+impl<Kind, InternalFormat> Texture<target::D2MultiSampleArray, Kind, InternalFormat>
+where
+    Kind: storage::marker::Storage<Target=target::D2MultiSampleArray, Signature = storage::signature::Storage3DMultisample>,
+    InternalFormat: image::marker::Storable,
+    <InternalFormat::Output as glsl::sampler::Output>::Kind: MaxSamples,
+{
+    /// Create array of `layers` multisample images with given number of `samples` per texel.
+    pub fn create_multisample_2d_array(samples: usize, width: usize, height: usize, layers: usize, fixed_sample_locations: bool) -> Self {
+        check_samples::<InternalFormat>(samples);
+        let mut object = ObjectBase::default();
+        let binder = object.bind();
+
+        let storage = Storage::storage_3d_multisample(&binder, samples, width, height, layers, fixed_sample_locations);
+        Self {
+            object,
+            state: TextureState::new(storage),
+        }
+    }
+}
+
+impl<InternalFormat, GL> Texture<target::Buffer, gl::Buffer<target::Buffer, GL>, InternalFormat>
+where
+    InternalFormat: image::marker::Format,
+    GL: pixel::Pixel<Components = <InternalFormat::BaseFormat as BaseFormat>::Components, Type = InternalFormat::ComponentType>,
+{
+    /// Create buffer texture whose texels are elements of `buffer` in given range.
+    ///
+    /// Elements must have as many components of the same type as texels of the internal format.
+    pub fn from_buffer(buffer: &gl::Buffer<target::Buffer, GL>, range: impl std::ops::RangeBounds<usize>) -> Self
+    where
+        InternalFormat: valid::ForBufferTexture,
+    {
+        let (start, end) = storage::range("buffer", buffer.len(), range);
+        if start == end {
+            panic!("range {start}..{end} of buffer with {} elements is empty", buffer.len());
+        }
+        let [mut max_texels, mut offset_alignment] = [0; 2];
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetIntegerv(glb::MAX_TEXTURE_BUFFER_SIZE, &mut max_texels);
+                glb::GetIntegerv(glb::TEXTURE_BUFFER_OFFSET_ALIGNMENT, &mut offset_alignment);
+            }
+        }
+        if end - start > max_texels as usize {
+            panic!("buffer texture of {} texels exceeds GL_MAX_TEXTURE_BUFFER_SIZE of {max_texels}", end - start);
+        }
+        let offset = start * std::mem::size_of::<GL>();
+        if !offset.is_multiple_of(offset_alignment as usize) {
+            panic!("buffer offset of {offset} bytes is not aligned to GL_TEXTURE_BUFFER_OFFSET_ALIGNMENT of {offset_alignment}");
+        }
+
+        let object = ObjectBase::<TextureObject<target::Buffer>>::default();
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureBufferRange(
+                    object.name(),
+                    InternalFormat::ID,
+                    buffer.name(),
+                    offset as _,
+                    ((end - start) * std::mem::size_of::<GL>()) as _,
+                );
+            }
+        }
+        Self { object, state: TextureState::new(Storage::view([end - start], 1)) }
     }
 }
 
 #[derive(dm::Deref, dm::DerefMut)]
-pub struct TextureUnit<Target, Kind, InternalFormat, const INDEX: usize>(Texture<Target, Kind, InternalFormat>)
+pub struct TextureUnit<Target, Kind, InternalFormat, const INDEX: usize, const MIPMAPS: bool = false>(Texture<Target, Kind, InternalFormat, MIPMAPS>)
 where
     Target: target::Target, 
     Kind: storage::marker::Kind<Target = Target>, 
     InternalFormat: texture::image::marker::Format,
 ;
 
-impl<Target, Kind, InternalFormat, const INDEX: usize> TextureUnit<Target, Kind, InternalFormat, INDEX>
+impl<Target, Kind, InternalFormat, const INDEX: usize, const MIPMAPS: bool> TextureUnit<Target, Kind, InternalFormat, INDEX, MIPMAPS>
 where
     Target: target::Target, 
     Kind: storage::marker::Kind<Target = Target>, 
     InternalFormat: texture::image::marker::Format,
 {
-    pub fn new<const N: usize>(texture: Texture<Target, Kind, InternalFormat>) -> gl::Result<TextureUnit<Target, Kind, InternalFormat, N>> {
+    pub fn new<const N: usize>(texture: Texture<Target, Kind, InternalFormat, MIPMAPS>) -> gl::Result<TextureUnit<Target, Kind, InternalFormat, N, MIPMAPS>> {
         let binder = texture.bind();
         gl::call! {
             [propagate]
@@ -281,13 +1131,13 @@ where
     }
 }
 
-impl<Target, Kind, InternalFormat, const INDEX: usize> gl::program::Resource for TextureUnit<Target, Kind, InternalFormat, INDEX>
+impl<Target, Kind, InternalFormat, const INDEX: usize, const MIPMAPS: bool> gl::program::Resource for TextureUnit<Target, Kind, InternalFormat, INDEX, MIPMAPS>
 where
     Target: target::Target, 
     Kind: storage::marker::Kind<Target = Target>, 
     InternalFormat: texture::image::marker::Format,
 {
-    type UniformVariable = glsl::GSampler<Target, InternalFormat::Output>;
+    type UniformVariable = glsl::GSampler<<InternalFormat::Sampling as image::marker::Sampling>::Target<Target>, InternalFormat::Output>;
     
     fn opaque_uniform_variable<const BINDING: usize>(&self) -> glsl::variable::OpaqueUniformVariable<Self::UniformVariable, BINDING> {
         glsl::variable::OpaqueUniformVariable::default()
@@ -314,7 +1164,7 @@ impl Binders for () {
     fn binders(&self) -> Self::Binders { () }
 }
 
-impl<H, Target, Kind, InternalFormat, const BINDING: usize> Binders for (H, &TextureUnit<Target, Kind, InternalFormat, BINDING>)
+impl<H, Target, Kind, InternalFormat, const BINDING: usize, const MIPMAPS: bool> Binders for (H, &TextureUnit<Target, Kind, InternalFormat, BINDING, MIPMAPS>)
 where
     H: Binders,
     Target: texture::Target,
@@ -335,7 +1185,7 @@ impl Default for TextureUnits<()> {
 }
 
 impl<Handles> TextureUnits<Handles> where Handles: Binders {
-    pub fn add<Target, Kind, InternalFormat, const BINDING: usize>(self, unit: &TextureUnit<Target, Kind, InternalFormat, BINDING>) -> TextureUnits<(Handles, &TextureUnit<Target, Kind, InternalFormat, BINDING>)> 
+    pub fn add<Target, Kind, InternalFormat, const BINDING: usize, const MIPMAPS: bool>(self, unit: &TextureUnit<Target, Kind, InternalFormat, BINDING, MIPMAPS>) -> TextureUnits<(Handles, &TextureUnit<Target, Kind, InternalFormat, BINDING, MIPMAPS>)> 
     where
         Target: texture::Target,
         Kind: texture::storage::marker::Kind<Target = Target>,
@@ -0,0 +1,287 @@
+//! Sampling parameters stored in texture object.
+//!
+//! Parameters are used whenever texture is sampled through a texture unit without [`Sampler`](super::Sampler) bound.
+//! Only parameters meaningful for the target are exposed: multisample and buffer textures are never filtered,
+//! rectangle textures cannot be mipmapped and only clamp their coordinates.
+
+use crate::prelude::internal::*;
+
+use crate::gl;
+use crate::glsl;
+use gl::state::Comparison;
+use gl::texture::{self, filter, image, storage, target, Texture};
+
+/// Targets whose textures are sampled with filtering and wrapping parameters.
+#[hi::marker]
+pub trait Filtered: texture::Target { }
+
+hi::denmark! { target::D1 as Filtered }
+hi::denmark! { target::D2 as Filtered }
+hi::denmark! { target::D3 as Filtered }
+hi::denmark! { target::D1Array as Filtered }
+hi::denmark! { target::D2Array as Filtered }
+hi::denmark! { target::Rectangle as Filtered }
+hi::denmark! { target::CubeMap as Filtered }
+hi::denmark! { target::CubeMapArray as Filtered }
+
+/// Targets whose texture coordinates can be wrapped with any mode, not only clamped.
+#[diagnostic::on_unimplemented(message = "`{Self}` textures only clamp their coordinates", note = "samplers of such textures are restricted to clamping with `Sampler::clamp`")]
+#[hi::marker]
+pub trait Repeatable: Filtered { }
+
+hi::denmark! { target::D1 as Repeatable }
+hi::denmark! { target::D2 as Repeatable }
+hi::denmark! { target::D3 as Repeatable }
+hi::denmark! { target::D1Array as Repeatable }
+hi::denmark! { target::D2Array as Repeatable }
+hi::denmark! { target::CubeMap as Repeatable }
+hi::denmark! { target::CubeMapArray as Repeatable }
+
+/// Wrap modes of texture target, one per texture coordinate which is not an array layer.
+pub trait Wrapping: Filtered {
+    type Mode: Into<filter::Wrap> + Copy;
+    type Modes: AsRef<[Self::Mode]>;
+}
+
+impl Wrapping for target::D1 { type Mode = filter::Wrap; type Modes = [filter::Wrap; 1]; }
+impl Wrapping for target::D2 { type Mode = filter::Wrap; type Modes = [filter::Wrap; 2]; }
+impl Wrapping for target::D3 { type Mode = filter::Wrap; type Modes = [filter::Wrap; 3]; }
+impl Wrapping for target::D1Array { type Mode = filter::Wrap; type Modes = [filter::Wrap; 1]; }
+impl Wrapping for target::D2Array { type Mode = filter::Wrap; type Modes = [filter::Wrap; 2]; }
+impl Wrapping for target::CubeMap { type Mode = filter::Wrap; type Modes = [filter::Wrap; 2]; }
+impl Wrapping for target::CubeMapArray { type Mode = filter::Wrap; type Modes = [filter::Wrap; 2]; }
+impl Wrapping for target::Rectangle { type Mode = Clamp; type Modes = [Clamp; 2]; }
+
+/// Wrap modes available for rectangle textures.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Clamp {
+    ToEdge = glb::CLAMP_TO_EDGE,
+    ToBorder = glb::CLAMP_TO_BORDER,
+}
+
+impl From<Clamp> for filter::Wrap {
+    fn from(clamp: Clamp) -> Self {
+        match clamp {
+            Clamp::ToEdge => Self::ClampToEdge,
+            Clamp::ToBorder => Self::ClampToBorder,
+        }
+    }
+}
+
+/// Source of a component returned by sampler.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Swizzle {
+    Red = glb::RED,
+    Green = glb::GREEN,
+    Blue = glb::BLUE,
+    Alpha = glb::ALPHA,
+    Zero = glb::ZERO,
+    One = glb::ONE,
+}
+
+/// Base formats storing both depth and stencil.
+#[diagnostic::on_unimplemented(message = "`{Self}` images do not store both depth and stencil")]
+#[hi::marker]
+pub trait ForDepthStencilMode: image::marker::BaseFormat { }
+
+hi::denmark! { image::format::DEPTH_STENCIL as ForDepthStencilMode }
+
+/// Base formats containing depth which can be compared against reference value.
+#[diagnostic::on_unimplemented(message = "`{Self}` images do not store depth and cannot be sampled by shadow samplers")]
+#[hi::marker]
+pub trait ForShadow: image::marker::BaseFormat { }
+
+hi::denmark! { image::format::DEPTH_COMPONENT as ForShadow }
+hi::denmark! { image::format::DEPTH_STENCIL as ForShadow }
+
+/// Sampler outputs paired with parameter entry points taking border color of matching type.
+pub trait BorderColor: glsl::sampler::Output + Sized {
+    fn set(texture: u32, color: &[Self; 4]);
+    fn set_sampler(sampler: u32, color: &[Self; 4]);
+}
+
+impl BorderColor for f32 {
+    fn set(texture: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameterfv(texture, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+
+    fn set_sampler(sampler: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::SamplerParameterfv(sampler, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+}
+
+impl BorderColor for i32 {
+    fn set(texture: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameterIiv(texture, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+
+    fn set_sampler(sampler: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::SamplerParameterIiv(sampler, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+}
+
+impl BorderColor for u32 {
+    fn set(texture: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameterIuiv(texture, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+
+    fn set_sampler(sampler: u32, color: &[Self; 4]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::SamplerParameterIuiv(sampler, glb::TEXTURE_BORDER_COLOR, color.as_ptr());
+            }
+        }
+    }
+}
+
+/// Replace driver default minification filter, which reads mipmaps, for texture which has none.
+pub(super) fn without_mipmaps(texture: u32) {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::TextureParameteri(texture, glb::TEXTURE_MIN_FILTER, glb::LINEAR as _);
+        }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Filtered,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    fn parameter_i(&self, name: u32, value: i32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameteri(self.name(), name, value);
+            }
+        }
+    }
+
+    /// Minification filter. Mipmapped filters require texture with mipmaps.
+    pub fn min_filter<M: filter::ForMipmaps<MIPMAPS>>(&mut self, _: M) {
+        self.parameter_i(glb::TEXTURE_MIN_FILTER, M::ID as _);
+    }
+
+    pub fn mag_filter<M: filter::Mag>(&mut self, _: M) {
+        self.parameter_i(glb::TEXTURE_MAG_FILTER, M::ID as _);
+    }
+
+    /// Wrap modes along `s`, `t` and `r` coordinates, as many as the target has.
+    pub fn wrap(&mut self, modes: T::Modes)
+    where
+        T: Wrapping,
+    {
+        let names = [glb::TEXTURE_WRAP_S, glb::TEXTURE_WRAP_T, glb::TEXTURE_WRAP_R];
+        for (&name, &mode) in names.iter().zip(modes.as_ref()) {
+            self.parameter_i(name, mode.into() as _);
+        }
+    }
+
+    /// Select sources of red, green, blue and alpha components returned by sampler.
+    pub fn swizzle(&mut self, swizzle: [Swizzle; 4]) {
+        let swizzle = swizzle.map(|component| component as i32);
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::TextureParameteriv(self.name(), glb::TEXTURE_SWIZZLE_RGBA, swizzle.as_ptr());
+            }
+        }
+    }
+
+    /// Color returned for coordinates clamped to border, of the same kind as sampled values.
+    pub fn border_color(&mut self, color: [InternalFormat::Output; 4])
+    where
+        InternalFormat::Output: BorderColor,
+    {
+        BorderColor::set(self.name(), &color);
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Filtered,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format<Sampling = image::marker::Plain>,
+    InternalFormat::BaseFormat: ForShadow,
+{
+    /// Compare sampled depth against reference value using `function`.
+    ///
+    /// Returned texture can only be sampled by shadow samplers.
+    pub fn compare(self, function: Comparison) -> Texture<T, K, image::Compared<InternalFormat>, MIPMAPS> {
+        self.parameter_i(glb::TEXTURE_COMPARE_MODE, glb::COMPARE_REF_TO_TEXTURE as _);
+        self.parameter_i(glb::TEXTURE_COMPARE_FUNC, function as _);
+        Texture { object: self.object, state: self.state.cast() }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, image::Compared<InternalFormat>, MIPMAPS>
+where
+    T: Filtered,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Return to sampling stored depth values.
+    pub fn no_compare(self) -> Texture<T, K, InternalFormat, MIPMAPS> {
+        self.parameter_i(glb::TEXTURE_COMPARE_MODE, glb::NONE as _);
+        Texture { object: self.object, state: self.state.cast() }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, InternalFormat, MIPMAPS>
+where
+    T: Filtered,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format<Sampling = image::marker::Plain>,
+    InternalFormat::BaseFormat: ForDepthStencilMode,
+{
+    /// Sample stencil indices of depth-stencil texture instead of depth.
+    ///
+    /// Returned texture can only be sampled by unsigned integer samplers.
+    pub fn sample_stencil(self) -> Texture<T, K, image::StencilSampled<InternalFormat>, MIPMAPS> {
+        self.parameter_i(glb::DEPTH_STENCIL_TEXTURE_MODE, glb::STENCIL_INDEX as _);
+        Texture { object: self.object, state: self.state.cast() }
+    }
+}
+
+impl<T, K, InternalFormat, const MIPMAPS: bool> Texture<T, K, image::StencilSampled<InternalFormat>, MIPMAPS>
+where
+    T: Filtered,
+    K: storage::marker::Kind<Target = T>,
+    InternalFormat: image::marker::Format,
+{
+    /// Return to sampling depth.
+    pub fn sample_depth(self) -> Texture<T, K, InternalFormat, MIPMAPS> {
+        self.parameter_i(glb::DEPTH_STENCIL_TEXTURE_MODE, glb::DEPTH_COMPONENT as _);
+        Texture { object: self.object, state: self.state.cast() }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::gl::{self, impl_token};
 use crate::prelude::internal::*;
-use crate::{ext, gl::types::float16};
+use crate::{ext, gl::types::{float16, urgb565, urgb10a2, u10f10f11f, u24u8, f32u24u8}};
 
 use super::image;
 
@@ -38,6 +38,12 @@ pub mod ty {
     impl_type! { i32     => Aggregate }
     impl_type! { float16 => Aggregate }
     impl_type! { f32     => Aggregate }
+
+    impl_type! { urgb565    => Standalone }
+    impl_type! { urgb10a2   => Standalone }
+    impl_type! { u10f10f11f => Standalone }
+    impl_type! { u24u8      => Standalone }
+    impl_type! { f32u24u8   => Standalone }
 }
 
 pub trait Format {
@@ -67,7 +73,7 @@ pub mod format {
 
 /// Implementations of 'Channels`.
 pub mod channels {
-    use image::format::Components;
+    use crate::gl::texture::image::format::Components;
 
     use super::*;
     use crate::gl::impl_token;
@@ -106,6 +112,9 @@ pub mod channels {
     impl_channels! { BGR   [3] }
     impl_channels! { RGBA  [4] }
     impl_channels! { BGRA  [4] }
+    impl_channels! { DepthComponent [1] }
+    impl_channels! { StencilIndex   [1] }
+    impl_channels! { DepthStencil   [2] }
 
     // impl_token! { StencilIndex   as Channels => STENCIL_INDEX    }
     // impl_token! { DepthComponent as Channels => DEPTH_COMPONENT  }
@@ -163,8 +172,34 @@ pub mod valid {
         ForImageBaseFormat<image::format::RGBA> 
     }
 
+    hi::denmark! { channels::DepthComponent as
+        ForImageBaseFormat<image::format::DEPTH_COMPONENT>,
+        ForImageBaseFormat<image::format::DEPTH_STENCIL>
+    }
+
+    hi::denmark! { channels::StencilIndex as
+        ForImageBaseFormat<image::format::STENCIL_INDEX>
+    }
+
+    hi::denmark! { channels::DepthStencil as
+        ForImageBaseFormat<image::format::DEPTH_STENCIL>
+    }
+
     /// Formats that are valid for pixel transfer for given configuration of target channels.
+    #[diagnostic::on_unimplemented(message = "`{Self}` pixels cannot be transferred as `{Channels}` channels", note = "packed types only pair with channels in their packing order")]
     pub trait ForChannels<Channels: channels::Channels>: Pixel { }
+
+    impl<T, const N: usize, C> ForChannels<C> for [T; N]
+    where
+        [T; N]: Pixel,
+        C: channels::Channels,
+    { }
+
+    hi::denmark! { urgb565 as ForChannels<channels::RGB> }
+    hi::denmark! { urgb10a2 as ForChannels<channels::RGBA>, ForChannels<channels::BGRA> }
+    hi::denmark! { u10f10f11f as ForChannels<channels::RGB> }
+    hi::denmark! { u24u8 as ForChannels<channels::DepthStencil> }
+    hi::denmark! { f32u24u8 as ForChannels<channels::DepthStencil> }
 }
 
 pub trait FormatToken {
@@ -189,7 +224,61 @@ impl_token! { (channels::BGR  , gl::types::Integer ) as FormatToken =>  BGR_INTE
 impl_token! { (channels::RGBA , gl::types::Integer ) as FormatToken =>  RGBA_INTEGER  }
 impl_token! { (channels::BGRA , gl::types::Integer ) as FormatToken =>  BGRA_INTEGER  }
 
-pub trait Pixel: Format {
+impl_token! { (channels::DepthComponent, gl::types::Float   ) as FormatToken => DEPTH_COMPONENT }
+impl_token! { (channels::StencilIndex  , gl::types::Integer ) as FormatToken => STENCIL_INDEX   }
+impl_token! { (channels::DepthStencil  , gl::types::Float   ) as FormatToken => DEPTH_STENCIL   }
+
+impl_token! { (image::format::RED  , gl::types::Float   ) as FormatToken => RED          }
+impl_token! { (image::format::RG   , gl::types::Float   ) as FormatToken => RG           }
+impl_token! { (image::format::RGB  , gl::types::Float   ) as FormatToken => RGB          }
+impl_token! { (image::format::RGBA , gl::types::Float   ) as FormatToken => RGBA         }
+impl_token! { (image::format::RED  , gl::types::Integer ) as FormatToken => RED_INTEGER  }
+impl_token! { (image::format::RG   , gl::types::Integer ) as FormatToken => RG_INTEGER   }
+impl_token! { (image::format::RGB  , gl::types::Integer ) as FormatToken => RGB_INTEGER  }
+impl_token! { (image::format::RGBA , gl::types::Integer ) as FormatToken => RGBA_INTEGER }
+
+impl_token! { (image::format::DEPTH_COMPONENT, gl::types::Float   ) as FormatToken => DEPTH_COMPONENT }
+impl_token! { (image::format::DEPTH_STENCIL  , gl::types::Float   ) as FormatToken => DEPTH_STENCIL   }
+impl_token! { (image::format::STENCIL_INDEX  , gl::types::Integer ) as FormatToken => STENCIL_INDEX   }
+
+/// Largest pixel store alignment satisfied by rows of `row_size` bytes.
+pub(in crate::gl) const fn alignment(row_size: usize) -> i32 {
+    match row_size {
+        size if size % 8 == 0 => 8,
+        size if size % 4 == 0 => 4,
+        size if size % 2 == 0 => 2,
+        _ => 1,
+    }
+}
+
+/// Set layout of pixel rows packed into client memory by readbacks.
+pub(in crate::gl) fn pack_rows<P: Pixel>(width: usize) {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::PixelStorei(glb::PACK_ALIGNMENT, alignment(width * std::mem::size_of::<P>()));
+            glb::PixelStorei(glb::PACK_ROW_LENGTH, width as _);
+        }
+    }
+}
+
+/// Set layout of pixel rows unpacked from client memory by uploads.
+pub(in crate::gl) fn unpack_rows<P: Pixel>(width: usize) {
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::PixelStorei(glb::UNPACK_ALIGNMENT, alignment(width * std::mem::size_of::<P>()));
+            glb::PixelStorei(glb::UNPACK_ROW_LENGTH, width as _);
+            glb::PixelStorei(glb::UNPACK_IMAGE_HEIGHT, 0);
+        }
+    }
+}
+
+/// Client side representation of a single pixel.
+///
+/// # Safety
+/// Implementors must consist of `Type` values only, so that all-zero bits are a valid value.
+pub unsafe trait Pixel: Format {
     type Type: Type;
 
     fn type_token() -> u32 {
@@ -197,7 +286,15 @@ pub trait Pixel: Format {
     }
 }
 
-impl<T, const N: usize> Pixel for [T; N]
+/// Buffer of `length` zeroed pixels to read into.
+pub(in crate::gl) fn zeroed<P: Pixel>(length: usize) -> Vec<P> {
+    let mut pixels = Vec::with_capacity(length);
+    // SAFETY: pixels consist of plain numbers for which all-zero bits are valid.
+    pixels.resize_with(length, || unsafe { std::mem::zeroed() });
+    pixels
+}
+
+unsafe impl<T, const N: usize> Pixel for [T; N]
 where
     T: Type<Usage=ty::Aggregate>,
     Const<N>: image::format::Components,
@@ -205,3 +302,38 @@ where
 {
     type Type = T;
 }
+
+/// Packed types holding all channels of a pixel in a single value.
+macro_rules! impl_packed_pixel {
+    ($ty:ty [$components:literal]) => {
+        impl Format for $ty {
+            type Components = Const<$components>;
+        }
+
+        unsafe impl Pixel for $ty {
+            type Type = Self;
+        }
+    };
+}
+
+impl_packed_pixel! { urgb565    [3] }
+impl_packed_pixel! { urgb10a2   [4] }
+impl_packed_pixel! { u10f10f11f [3] }
+impl_packed_pixel! { u24u8      [2] }
+impl_packed_pixel! { f32u24u8   [2] }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alignment_is_largest_dividing_row_size() {
+        assert_eq!(alignment(16), 8);
+        assert_eq!(alignment(12), 4);
+        assert_eq!(alignment(6), 2);
+        assert_eq!(alignment(3), 1);
+        // Rows of 5 RGB u8 pixels.
+        assert_eq!(alignment(5 * std::mem::size_of::<[u8; 3]>()), 1);
+        assert_eq!(alignment(0), 8);
+    }
+}
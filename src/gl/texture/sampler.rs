@@ -1,4 +1,255 @@
+//! OpenGL Sampler Object.
+//!
+//! Sampler holds sampling parameters separately from texture storage. When bound to a texture unit its parameters
+//! override parameters of the texture bound there, so the same texture can be sampled differently by different programs.
 
-pub struct Sampler {
-    
+use crate::prelude::internal::*;
+
+use crate::gl;
+use gl::object::*;
+use gl::state::Comparison;
+use gl::texture::{self, filter, image, parameter};
+
+/// `GL_TEXTURE_MAX_ANISOTROPY`, core since OpenGL 4.6.
+const TEXTURE_MAX_ANISOTROPY: u32 = 0x84FE;
+
+#[hi::mark(PartialObject)]
+pub enum SamplerObject { }
+
+unsafe impl Allocator for SamplerObject {
+    fn allocate(names: &mut [u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::CreateSamplers(names.len() as _, names.as_mut_ptr());
+            }
+        }
+    }
+
+    fn free(names: &[u32]) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::DeleteSamplers(names.len() as _, names.as_ptr());
+            }
+        }
+    }
+}
+
+/// Wrap modes a sampler can use to sample textures of target `T`.
+#[diagnostic::on_unimplemented(message = "sampler wrapping with `{Self}` cannot sample `{T}` textures")]
+pub trait ForTarget<T: texture::Target>: Into<filter::Wrap> + Copy { }
+
+impl<T: parameter::Repeatable> ForTarget<T> for filter::Wrap { }
+impl<T: parameter::Filtered> ForTarget<T> for parameter::Clamp { }
+
+/// Border colors of sampler usable with textures whose samplers return `Output`.
+///
+/// Sampler without border color set, `()`, keeps the default of zeros which fits any texture.
+#[diagnostic::on_unimplemented(message = "sampler with `{Self}` border color cannot sample textures returning `{Output}`")]
+pub trait BorderFor<Output> { }
+
+impl<Output> BorderFor<Output> for () { }
+impl BorderFor<f32> for f32 { }
+impl BorderFor<i32> for i32 { }
+impl BorderFor<u32> for u32 { }
+
+/// Sampler object using minification filter `Min`.
+///
+/// Filter is tracked in the type so that mipmapped filtering cannot be applied to texture without mipmaps.
+/// Comparison mode (`Sampling`), wrap modes (`Mode`) and type of border color (`Border`) are tracked as well,
+/// so that sampler can only be bound to units whose texture it can sample.
+#[derive(dm::Deref)]
+pub struct Sampler<Min = filter::Linear, Sampling = image::marker::Plain, Mode = filter::Wrap, Border = ()>
+where
+    Min: filter::Min,
+    Sampling: image::marker::Sampling,
+    Mode: Into<filter::Wrap> + Copy,
+{
+    #[deref]
+    object: ObjectBase<SamplerObject>,
+    state: PhantomData<(Min, Sampling, Mode, Border)>,
+}
+
+impl Sampler {
+    /// Create sampler with linear minification and magnification.
+    pub fn create() -> Self {
+        Self { object: Default::default(), state: PhantomData }
+            .min_filter(filter::Linear)
+            .mag_filter(filter::Linear)
+    }
+}
+
+impl<Min, Sampling, Mode, Border> Sampler<Min, Sampling, Mode, Border>
+where
+    Min: filter::Min,
+    Sampling: image::marker::Sampling,
+    Mode: Into<filter::Wrap> + Copy,
+{
+    fn parameter_i(&self, name: u32, value: i32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::SamplerParameteri(self.name(), name, value);
+            }
+        }
+    }
+
+    fn parameter_f(&self, name: u32, value: f32) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::SamplerParameterf(self.name(), name, value);
+            }
+        }
+    }
+
+    fn cast<M, S, W, B>(self) -> Sampler<M, S, W, B>
+    where
+        M: filter::Min,
+        S: image::marker::Sampling,
+        W: Into<filter::Wrap> + Copy,
+    {
+        Sampler { object: self.object, state: PhantomData }
+    }
+
+    pub fn min_filter<M: filter::Min>(self, _: M) -> Sampler<M, Sampling, Mode, Border> {
+        self.parameter_i(glb::TEXTURE_MIN_FILTER, M::ID as _);
+        self.cast()
+    }
+
+    pub fn mag_filter<M: filter::Mag>(self, _: M) -> Self {
+        self.parameter_i(glb::TEXTURE_MAG_FILTER, M::ID as _);
+        self
+    }
+
+    /// Wrap mode along `s` texture coordinate.
+    pub fn wrap_s(self, wrap: Mode) -> Self {
+        self.parameter_i(glb::TEXTURE_WRAP_S, wrap.into() as _);
+        self
+    }
+
+    /// Wrap mode along `t` texture coordinate.
+    pub fn wrap_t(self, wrap: Mode) -> Self {
+        self.parameter_i(glb::TEXTURE_WRAP_T, wrap.into() as _);
+        self
+    }
+
+    /// Wrap mode along `r` texture coordinate.
+    pub fn wrap_r(self, wrap: Mode) -> Self {
+        self.parameter_i(glb::TEXTURE_WRAP_R, wrap.into() as _);
+        self
+    }
+
+    /// Wrap mode along all texture coordinates.
+    pub fn wrap(self, wrap: Mode) -> Self {
+        self.wrap_s(wrap).wrap_t(wrap).wrap_r(wrap)
+    }
+
+    /// Clamp all texture coordinates with `clamp`.
+    ///
+    /// Only clamping sampler can be bound to unit of rectangle texture. Its wrap modes are then restricted to clamping.
+    pub fn clamp(self, clamp: parameter::Clamp) -> Sampler<Min, Sampling, parameter::Clamp, Border> {
+        self.cast().wrap(clamp)
+    }
+
+    /// Bias added to level of detail computed during sampling.
+    pub fn lod_bias(self, bias: f32) -> Self {
+        self.parameter_f(glb::TEXTURE_LOD_BIAS, bias);
+        self
+    }
+
+    /// Clamp level of detail to given range.
+    pub fn lod_range(self, range: std::ops::RangeInclusive<f32>) -> Self {
+        if range.start() > range.end() {
+            panic!("level of detail range {range:?} is empty");
+        }
+        self.parameter_f(glb::TEXTURE_MIN_LOD, *range.start());
+        self.parameter_f(glb::TEXTURE_MAX_LOD, *range.end());
+        self
+    }
+
+    /// Maximal degree of anisotropy, `1.0` disables anisotropic filtering.
+    pub fn anisotropy(self, degree: f32) -> Self {
+        if degree < 1.0 {
+            panic!("degree of anisotropy {degree} is lower than 1.0");
+        }
+        self.parameter_f(TEXTURE_MAX_ANISOTROPY, degree);
+        self
+    }
+
+    /// Color returned for coordinates clamped to border, of the same kind as values sampled from textures it is bound for.
+    pub fn border_color<Output>(self, color: [Output; 4]) -> Sampler<Min, Sampling, Mode, Output>
+    where
+        Output: parameter::BorderColor,
+    {
+        Output::set_sampler(self.name(), &color);
+        self.cast()
+    }
+
+    /// Bind sampler to the texture unit of `unit`. Sampler stays bound until returned guard is dropped.
+    pub fn bind_to<'sampler, Target, Kind, InternalFormat, const INDEX: usize, const MIPMAPS: bool>(
+        &'sampler self,
+        _: &texture::TextureUnit<Target, Kind, InternalFormat, INDEX, MIPMAPS>,
+    ) -> SamplerBinding<'sampler, INDEX>
+    where
+        Target: texture::Target,
+        Kind: texture::storage::marker::Kind<Target = Target>,
+        InternalFormat: texture::image::marker::Format,
+        InternalFormat::Sampling: image::marker::Sampling<Mode = Sampling>,
+        Min: filter::ForMipmaps<MIPMAPS>,
+        Mode: ForTarget<Target>,
+        Border: BorderFor<InternalFormat::Output>,
+    {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindSampler(INDEX as _, self.name());
+            }
+        }
+        SamplerBinding { sampler: PhantomData }
+    }
+}
+
+impl<Min, Mode, Border> Sampler<Min, image::marker::Plain, Mode, Border>
+where
+    Min: filter::Min,
+    Mode: Into<filter::Wrap> + Copy,
+{
+    /// Compare sampled depth against reference value instead of returning it, as shadow samplers do.
+    ///
+    /// Returned sampler can only be bound to units of textures returned by [`Texture::compare`](texture::Texture::compare).
+    pub fn compare(self, function: Comparison) -> Sampler<Min, image::marker::Comparing, Mode, Border> {
+        self.parameter_i(glb::TEXTURE_COMPARE_MODE, glb::COMPARE_REF_TO_TEXTURE as _);
+        self.parameter_i(glb::TEXTURE_COMPARE_FUNC, function as _);
+        self.cast()
+    }
+}
+
+impl<Min, Mode, Border> Sampler<Min, image::marker::Comparing, Mode, Border>
+where
+    Min: filter::Min,
+    Mode: Into<filter::Wrap> + Copy,
+{
+    /// Return sampled depth as is.
+    pub fn no_compare(self) -> Sampler<Min, image::marker::Plain, Mode, Border> {
+        self.parameter_i(glb::TEXTURE_COMPARE_MODE, glb::NONE as _);
+        self.cast()
+    }
+}
+
+/// Sampler bound to texture unit `INDEX`. Unit falls back to parameters of its texture when dropped.
+pub struct SamplerBinding<'sampler, const INDEX: usize> {
+    sampler: PhantomData<&'sampler ObjectBase<SamplerObject>>,
+}
+
+impl<const INDEX: usize> Drop for SamplerBinding<'_, INDEX> {
+    fn drop(&mut self) {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindSampler(INDEX as _, 0);
+            }
+        }
+    }
 }
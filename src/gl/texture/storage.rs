@@ -62,6 +62,45 @@ where
     target: PhantomData<Target>,
     internal_format: PhantomData<InternalFormat>,
     dimensions: Target::Dimensions,
+    levels: usize,
+}
+
+impl<Target, InternalFormat, const CONTAINS_MIPMAPS: bool> Layout<Target, InternalFormat, CONTAINS_MIPMAPS>
+where
+    Target: texture::Target,
+{
+    fn new(dimensions: Target::Dimensions, levels: usize) -> Self {
+        let max = max_levels::<Target>(&dimensions);
+        if levels == 0 || levels > max {
+            panic!("level count {levels} is outside of 1..={max} for dimensions {:?}", dimensions.as_ref());
+        }
+        if !CONTAINS_MIPMAPS && levels != 1 {
+            panic!("storage without mipmaps must have exactly 1 level, got {levels}");
+        }
+        if let &[width, height, ref rest @ ..] = dimensions.as_ref() {
+            let cube = Target::ID == glb::TEXTURE_CUBE_MAP || Target::ID == glb::TEXTURE_CUBE_MAP_ARRAY;
+            if cube && width != height {
+                panic!("cube map faces must be square, got {width}x{height}");
+            }
+            if let &[layer_faces] = rest {
+                if Target::ID == glb::TEXTURE_CUBE_MAP_ARRAY && layer_faces % 6 != 0 {
+                    panic!("cube map array depth counts layer-faces and must be a multiple of 6, got {layer_faces}");
+                }
+            }
+        }
+        Self {
+            target: PhantomData,
+            internal_format: PhantomData,
+            dimensions,
+            levels,
+        }
+    }
+}
+
+/// Number of levels in full mipmap chain of image with given base level dimensions.
+pub fn max_levels<Target: texture::Target>(dimensions: &Target::Dimensions) -> usize {
+    let largest = dimensions.as_ref()[..Target::MIPMAPPED_AXES].iter().copied().max().unwrap_or(1).max(1);
+    largest.ilog2() as usize + 1
 }
 
 /// Abstraction of texture storage.
@@ -79,29 +118,82 @@ where
     layout: Layout<Target, InternalFormat, CONTAINS_MIPMAPS>
 }
 
-impl<Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool, const DIM: usize> Storage<Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
+impl<Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
-    Const<DIM>: texture::valid::TextureDim,
-    Target: texture::Target + Dimensionality<Dimensions = [usize; DIM]>,
-    Kind: marker::Storage<Target=Target>,  
+    Target: texture::Target,
+    Kind: marker::Kind<Target=Target>,
     InternalFormat: image::marker::Format,
 {
-    fn range(len: usize, span: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
-        let start = match span.start_bound() {
-            std::ops::Bound::Included(&n) => n,
-            std::ops::Bound::Excluded(&n) => n + 1,
-            std::ops::Bound::Unbounded => 0,
-        };
-        let end = match span.end_bound() {
-            std::ops::Bound::Included(&n) => n,
-            std::ops::Bound::Excluded(&n) => n - 1,
-            std::ops::Bound::Unbounded => len,
-        };
-        (start, end)
+    /// Dimensions of the base level of the texture image.
+    pub fn dimensions(&self) -> &Target::Dimensions {
+        &self.layout.dimensions
+    }
+
+    /// Layout of storage owned by another object, viewed texture or buffer.
+    pub(in crate::gl::texture) fn view(dimensions: Target::Dimensions, levels: usize) -> Self {
+        Self { kind: PhantomData, layout: Layout::new(dimensions, levels) }
+    }
+
+    /// Reinterpret storage as having internal format `F` which must describe the same memory.
+    pub(in crate::gl::texture) fn cast<F: image::marker::Format>(self) -> Storage<Target, Kind, F, CONTAINS_MIPMAPS> {
+        let Layout { dimensions, levels, .. } = self.layout;
+        Storage {
+            kind: PhantomData,
+            layout: Layout { target: PhantomData, internal_format: PhantomData, dimensions, levels },
+        }
+    }
+
+    /// Number of mipmap levels allocated for the texture image.
+    pub fn levels(&self) -> usize {
+        self.layout.levels
+    }
+
+    /// Dimensions of given mipmap level. Array layers are the same on every level.
+    pub fn level_dimensions(&self, level: usize) -> Target::Dimensions {
+        if level >= self.layout.levels {
+            panic!("level {level} is out of bounds of storage with {} levels", self.layout.levels);
+        }
+        let mut dimensions = self.layout.dimensions;
+        for dimension in &mut dimensions.as_mut()[..Target::MIPMAPPED_AXES] {
+            *dimension = (*dimension >> level).max(1);
+        }
+        dimensions
+    }
+}
+
+/// Resolve range bounds into half-open `(start, end)` pair of indices along `axis` of length `len`.
+///
+/// Panics if the range is reversed or extends out of bounds of the axis.
+pub(in crate::gl) fn range(axis: &str, len: usize, span: impl std::ops::RangeBounds<usize>) -> (usize, usize) {
+    let past = |n: usize| n.checked_add(1).unwrap_or_else(|| panic!("{axis} range bound {n} is out of bounds of {len}"));
+    let start = match span.start_bound() {
+        std::ops::Bound::Included(&n) => n,
+        std::ops::Bound::Excluded(&n) => past(n),
+        std::ops::Bound::Unbounded => 0,
+    };
+    let end = match span.end_bound() {
+        std::ops::Bound::Included(&n) => past(n),
+        std::ops::Bound::Excluded(&n) => n,
+        std::ops::Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!("{axis} range {start}..{end} is reversed");
+    }
+    if end > len {
+        panic!("{axis} range {start}..{end} extends out of bounds of {len}");
+    }
+    (start, end)
+}
+
+/// Panic unless `len` client pixels exactly cover region of given extent.
+pub(in crate::gl) fn check_pixels<const N: usize>(len: usize, extent: [usize; N]) {
+    if len != extent.iter().product() {
+        let extent = extent.map(|length| length.to_string()).join("x");
+        panic!("{len} pixels do not match {extent} region");
     }
 }
 
-impl<D1Target, Kind, InternalFormat> Storage<D1Target, Kind, InternalFormat, false>
+impl<D1Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D1Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
     D1Target: texture::Target<Dimensions = [usize; 1]>,
     Kind: marker::Storage<Target=D1Target>,
@@ -109,29 +201,30 @@ where
 {
     pub fn sub_image_1d<
         Channels: pixel::channels::Channels,
-        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
     >(
         &mut self,
         _: &gl::object::Bind<texture::TextureObject<D1Target>>, 
+        level: usize,
         x_range: impl std::ops::RangeBounds<usize>, 
         pixels: &[Pixel]
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
-        let [width] = self.layout.dimensions;
-        let (start, end) = Self::range(width, x_range);
-        if end > width {
-            panic!("range {start}..={end} extends outside of texture width");
-        }
+        let [width] = self.level_dimensions(level);
+        let (start, end) = range("width", width, x_range);
         let length = end - start;
+        check_pixels(pixels.len(), [length]);
+        pixel::unpack_rows::<Pixel>(length);
         gl::call! {
             [panic]
             unsafe {
                 glb::TexSubImage1D(
                     D1Target::ID,
-                    0,
+                    level as _,
                     start as _,
                     length as _,
                     <(Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind) as pixel::FormatToken>::ID,
@@ -145,50 +238,105 @@ where
 
 impl<D2Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D2Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
-    D2Target: texture::Target<Dimensions = [usize; 2]>,
+    D2Target: texture::Target<Dimensions = [usize; 2]> + texture::valid::ForSubImage2D,
     Kind: marker::Storage<Target=D2Target>,
     InternalFormat: image::marker::Format,
 {
     pub fn sub_image_2d<
         Channels: pixel::channels::Channels,
-        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
     >(
         &mut self,
-        _: &gl::object::Bind<texture::TextureObject<D2Target>>,
+        binder: &gl::object::Bind<texture::TextureObject<D2Target>>,
+        level: usize,
         x_range: impl std::ops::RangeBounds<usize>, 
         y_range: impl std::ops::RangeBounds<usize>,
         pixels: &[Pixel]
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
-        let [width, height] = self.layout.dimensions;
-        let (x_start, x_end) = Self::range(width, x_range);
-        let (y_start, y_end) = Self::range(height, y_range);
-
-        let x_length = x_end - x_start;
-        let y_length = y_end - y_start;
+        let (offset, extent) = self.region_2d(level, x_range, y_range);
+        check_pixels(pixels.len(), extent);
+        self.sub_image_2d_raw::<Channels, Pixel>(binder, level, offset, extent, pixels.as_ptr() as *const _);
+    }
 
-        if x_end > width {
-            panic!("sub image width range {x_start}..={x_end} extends out of bounds");
+    /// Write pixels sourced from pixel unpack `buffer`, starting at pixel `offset`.
+    pub fn sub_image_2d_from_buffer<Channels, Pixel>(
+        &mut self,
+        binder: &gl::object::Bind<texture::TextureObject<D2Target>>,
+        level: usize,
+        buffer: &gl::Buffer<gl::buffer::PixelUnpack, Pixel>,
+        offset: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        if offset > buffer.len() {
+            panic!("offset {offset} is out of bounds of buffer with {} pixels", buffer.len());
         }
-        if y_end > height {
-            panic!("sub image height range {y_start}..={y_end} extends out of bounds");
+        let (region_offset, [x_length, y_length]) = self.region_2d(level, x_range, y_range);
+        // Buffer may hold more than one region, e.g. slots of a stream, only the pixels read have to be present.
+        let available = buffer.len() - offset;
+        if available < x_length * y_length {
+            panic!("{available} pixels are not enough for {x_length}x{y_length} region");
         }
+        let _unpack = buffer.bind();
+        // With pixel unpack buffer bound the pointer is interpreted as byte offset into the buffer.
+        let offset_bytes = offset * std::mem::size_of::<Pixel>();
+        self.sub_image_2d_raw::<Channels, Pixel>(binder, level, region_offset, [x_length, y_length], offset_bytes as *const _);
+    }
+
+    /// Resolve ranges within given level into `(offset, extent)` of the region.
+    fn region_2d(
+        &self,
+        level: usize,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+    ) -> ([usize; 2], [usize; 2]) {
+        let [width, height] = self.level_dimensions(level);
+        let (x_start, x_end) = range("width", width, x_range);
+        let (y_start, y_end) = range("height", height, y_range);
+        ([x_start, y_start], [x_end - x_start, y_end - y_start])
+    }
+
+    fn sub_image_2d_raw<Channels, Pixel>(
+        &mut self,
+        _: &gl::object::Bind<texture::TextureObject<D2Target>>,
+        level: usize,
+        [x_start, y_start]: [usize; 2],
+        [x_length, y_length]: [usize; 2],
+        pixels: *const std::ffi::c_void,
+    )
+    where
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::valid::ForChannels<Channels>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        pixel::unpack_rows::<Pixel>(x_length);
         gl::call! {
             [panic]
             unsafe {
                 glb::TexSubImage2D(
                     D2Target::ID,
-                    0,
+                    level as _,
                     x_start as _,
                     y_start as _,
                     x_length as _,
                     y_length as _,
                     <(Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind) as pixel::FormatToken>::ID,
                     Pixel::type_token(),
-                    pixels.as_ptr() as *const _,
+                    pixels,
                 );
             }
         }
@@ -197,16 +345,17 @@ where
 
 impl<D3Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D3Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
-    D3Target: texture::Target<Dimensions = [usize; 3]>,
+    D3Target: texture::Target<Dimensions = [usize; 3]> + texture::valid::ForPixelTransfer,
     Kind: marker::Storage<Target=D3Target>,
     InternalFormat: image::marker::Format,
 {
     pub fn sub_image_3d<
         Channels: pixel::channels::Channels,
-        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
     >(
         &mut self,
         _: &gl::object::Bind<texture::TextureObject<D3Target>>,
+        level: usize,
         x_range: impl std::ops::RangeBounds<usize>,
         y_range: impl std::ops::RangeBounds<usize>,
         z_range: impl std::ops::RangeBounds<usize>,
@@ -214,33 +363,26 @@ where
     )
     where
         Channels: pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        InternalFormat: image::marker::Uncompressed,
         (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
     {
-        let [width, height, depth] = self.layout.dimensions;
-        let (x_start, x_end) = Self::range(width, x_range);
-        let (y_start, y_end) = Self::range(height, y_range);
-        let (z_start, z_end) = Self::range(depth, z_range);
-
-        if x_end > width {
-            panic!("sub image width range {x_start}..={x_end} extends out of bounds");
-        }
-        if y_end > height {
-            panic!("sub image height range {y_start}..={y_end} extends out of bounds");
-        }
-        if z_end > depth {
-            panic!("sub image depth range {z_start}..={z_end} extends out of bounds");
-        }
+        let [width, height, depth] = self.level_dimensions(level);
+        let (x_start, x_end) = range("width", width, x_range);
+        let (y_start, y_end) = range("height", height, y_range);
+        let (z_start, z_end) = range("depth", depth, z_range);
 
         let x_length = x_end - x_start;
         let y_length = y_end - y_start;
         let z_length = z_end - z_start;
+        check_pixels(pixels.len(), [x_length, y_length, z_length]);
 
+        pixel::unpack_rows::<Pixel>(x_length);
         gl::call! {
             [panic]
             unsafe {
                 glb::TexSubImage3D(
                     D3Target::ID,
-                    0,
+                    level as _,
                     x_start as _,
                     y_start as _,
                     z_start as _,
@@ -256,74 +398,160 @@ where
     }
 }
 
-impl<D1Target, Kind, InternalFormat> Storage<D1Target, Kind, InternalFormat, false>
+impl<D1Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D1Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
     D1Target: texture::Target<Dimensions = [usize; 1]>,
     Kind: marker::Storage<Target=D1Target, Signature = signature::Storage1D>,
     InternalFormat: image::marker::Format,
 {
-    pub fn storage_1d(_: &gl::object::Bind<TextureObject<D1Target>>, width: usize) -> Self {
+    pub fn storage_1d(_: &gl::object::Bind<TextureObject<D1Target>>, levels: usize, width: usize) -> Self {
+        let layout = Layout::new([width], levels);
         gl::call! {
             [panic]
             unsafe {
-                Kind::ALLOCATOR(D1Target::ID, 1, InternalFormat::ID, width as _);
+                Kind::ALLOCATOR(D1Target::ID, levels as _, InternalFormat::ID, width as _);
             }
         }
         Self {
             kind: PhantomData,
-            layout: Layout {
-                target: PhantomData,
-                internal_format: PhantomData,
-                dimensions: [width],
-            },
+            layout,
         }
     }
 }
 
-impl<D2Target, Kind, InternalFormat> Storage<D2Target, Kind, InternalFormat, false>
+impl<D2Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D2Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
     D2Target: texture::Target<Dimensions = [usize; 2]>,
     Kind: marker::Storage<Target=D2Target, Signature = signature::Storage2D>,
     InternalFormat: image::marker::Format,
 {
-    pub fn storage_2d(_: &object::Bind<TextureObject<D2Target>>, width: usize, height: usize) -> Self {
+    pub fn storage_2d(_: &object::Bind<TextureObject<D2Target>>, levels: usize, width: usize, height: usize) -> Self {
+        let layout = Layout::new([width, height], levels);
         gl::call! {
             [panic]
             unsafe {
-                Kind::ALLOCATOR(D2Target::ID, 1, InternalFormat::ID, width as _, height as _);
+                Kind::ALLOCATOR(D2Target::ID, levels as _, InternalFormat::ID, width as _, height as _);
             }
         }
         Self {
             kind: PhantomData,
-            layout: Layout {
-                target: PhantomData,
-                internal_format: PhantomData,
-                dimensions: [width, height],
-            },
+            layout,
         }
     }
 }
 
-impl<D3Target, Kind, InternalFormat> Storage<D3Target, Kind, InternalFormat, false>
+impl<D3Target, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<D3Target, Kind, InternalFormat, CONTAINS_MIPMAPS>
 where
     D3Target: texture::Target<Dimensions = [usize; 3]>,
     Kind: marker::Storage<Target=D3Target, Signature = signature::Storage3D>,
     InternalFormat: image::marker::Format,
 {
-    pub fn storage_3d(_: &object::Bind<TextureObject<D3Target>>, width: usize, height: usize, depth: usize) -> Self {
+    pub fn storage_3d(_: &object::Bind<TextureObject<D3Target>>, levels: usize, width: usize, height: usize, depth: usize) -> Self {
+        let layout = Layout::new([width, height, depth], levels);
+        gl::call! {
+            [panic]
+            unsafe {
+                Kind::ALLOCATOR(D3Target::ID, levels as _, InternalFormat::ID, width as _, height as _, depth as _);
+            }
+        }
+        Self {
+            kind: PhantomData,
+            layout,
+        }
+    }
+}
+
+impl<D2Target, Kind, InternalFormat> Storage<D2Target, Kind, InternalFormat, false>
+where
+    D2Target: texture::Target<Dimensions = [usize; 2]>,
+    Kind: marker::Storage<Target=D2Target, Signature = signature::Storage2DMultisample>,
+    InternalFormat: image::marker::Format,
+{
+    pub fn storage_2d_multisample(_: &object::Bind<TextureObject<D2Target>>, samples: usize, width: usize, height: usize, fixed_sample_locations: bool) -> Self {
+        let layout = Layout::new([width, height], 1);
         gl::call! {
             [panic]
             unsafe {
-                Kind::ALLOCATOR(D3Target::ID, 1, InternalFormat::ID, width as _, height as _, depth as _);
+                Kind::ALLOCATOR(D2Target::ID, samples as _, InternalFormat::ID, width as _, height as _, fixed_sample_locations as _);
             }
         }
         Self {
             kind: PhantomData,
-            layout: Layout {
-                target: PhantomData,
-                internal_format: PhantomData,
-                dimensions: [width, height, depth],
-            },
+            layout,
+        }
+    }
+}
+
+impl<D3Target, Kind, InternalFormat> Storage<D3Target, Kind, InternalFormat, false>
+where
+    D3Target: texture::Target<Dimensions = [usize; 3]>,
+    Kind: marker::Storage<Target=D3Target, Signature = signature::Storage3DMultisample>,
+    InternalFormat: image::marker::Format,
+{
+    pub fn storage_3d_multisample(_: &object::Bind<TextureObject<D3Target>>, samples: usize, width: usize, height: usize, depth: usize, fixed_sample_locations: bool) -> Self {
+        let layout = Layout::new([width, height, depth], 1);
+        gl::call! {
+            [panic]
+            unsafe {
+                Kind::ALLOCATOR(D3Target::ID, samples as _, InternalFormat::ID, width as _, height as _, depth as _, fixed_sample_locations as _);
+            }
+        }
+        Self {
+            kind: PhantomData,
+            layout,
+        }
+    }
+}
+
+impl<T, Kind, InternalFormat, const CONTAINS_MIPMAPS: bool> Storage<T, Kind, InternalFormat, CONTAINS_MIPMAPS>
+where
+    T: texture::Target,
+    Kind: Specification<Target = T>,
+    InternalFormat: image::marker::Format,
+    (InternalFormat::BaseFormat, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+{
+    /// Specify `levels` levels of mutable storage with base level of given `dimensions`.
+    pub fn image(_: &object::Bind<TextureObject<T>>, levels: usize, dimensions: T::Dimensions) -> Self {
+        let storage = Self {
+            kind: PhantomData,
+            layout: Layout::new(dimensions, levels),
+        };
+        storage.specify();
+        storage
+    }
+
+    /// Re-specify all levels with base level of new `dimensions`, discarding their contents.
+    ///
+    /// Level count is clamped to the full mipmap chain of new dimensions.
+    pub fn reallocate(&mut self, _: &object::Bind<TextureObject<T>>, dimensions: T::Dimensions) {
+        let levels = self.layout.levels.min(max_levels::<T>(&dimensions));
+        self.layout = Layout::new(dimensions, levels);
+        self.specify();
+    }
+
+    fn specify(&self) {
+        let format = <(InternalFormat::BaseFormat, <InternalFormat::Output as glsl::sampler::Output>::Kind) as pixel::FormatToken>::ID;
+        // Storage is specified without data, type only has to be valid for the format.
+        let ty = if format == glb::DEPTH_STENCIL { glb::UNSIGNED_INT_24_8 } else { glb::UNSIGNED_BYTE };
+        for level in 0..self.layout.levels {
+            let dimensions = self.level_dimensions(level);
+            if T::ID == glb::TEXTURE_CUBE_MAP {
+                for face in target::CubeFace::ALL {
+                    Kind::specify(face as u32, level, InternalFormat::ID, dimensions, format, ty);
+                }
+            } else {
+                Kind::specify(T::ID, level, InternalFormat::ID, dimensions, format, ty);
+            }
+        }
+        // Levels of previous specification past the new count must not take part in mipmap completeness.
+        // Max level set by the user is kept if it still fits, fresh textures start at the default of 1000.
+        let mut max_level = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetTexParameteriv(T::ID, glb::TEXTURE_MAX_LEVEL, &mut max_level);
+                glb::TexParameteri(T::ID, glb::TEXTURE_MAX_LEVEL, (max_level as usize).min(self.layout.levels - 1) as _);
+            }
         }
     }
 }
@@ -448,6 +676,33 @@ pub trait AllocatorDispatch {
     const ALLOCATOR: Self::Signature;
 }
 
+/// Mutable storage whose levels are specified one at a time with `glTexImage*` allocator.
+pub trait Specification: marker::Storage {
+    fn specify(target: u32, level: usize, internal_format: u32, dimensions: <Self::Target as Dimensionality>::Dimensions, format: u32, ty: u32);
+}
+
+macro_rules! impl_specification {
+    ($axes:tt $($target:ty),+) => {
+        $(impl_specification! { @single $axes $target })+
+    };
+    (@single [$($axis:ident),+] $target:ty) => {
+        impl Specification for Mutable<$target> {
+            fn specify(target: u32, level: usize, internal_format: u32, [$($axis),+]: <$target as Dimensionality>::Dimensions, format: u32, ty: u32) {
+                gl::call! {
+                    [panic]
+                    unsafe {
+                        Self::ALLOCATOR(target, level as _, internal_format as _, $($axis as _),+, 0, format, ty, std::ptr::null());
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_specification! { [width] target::D1 }
+impl_specification! { [width, height] target::D2, target::Rectangle, target::CubeMap, target::D1Array }
+impl_specification! { [width, height, depth] target::D3, target::D2Array, target::CubeMapArray }
+
 macro_rules! dispatch_allocator {
     ([immutable] $target:ty => $function:path: $signature:path) => {
         impl AllocatorDispatch for Immutable<$target> {
@@ -496,9 +751,75 @@ dispatch_allocator! { [mutable] target::D2MultiSampleArray => glb::TexImage3DMul
 
 impl<GL> Dimensionality for gl::Buffer<texture::Buffer, GL> {
     type Dimensions = [usize; 1];
+    const MIPMAPPED_AXES: usize = 1;
 }
 
 impl<GL> AllocatorDispatch for gl::Buffer<texture::Buffer, GL> {
     type Signature = signature::Buffer;
     const ALLOCATOR: Self::Signature = glb::TexBuffer;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use super::{check_pixels, range};
+
+    #[test]
+    fn range_bounds_resolve_to_half_open_indices() {
+        assert_eq!(range("width", 8, ..), (0, 8));
+        assert_eq!(range("width", 8, 2..5), (2, 5));
+        assert_eq!(range("width", 8, 2..=5), (2, 6));
+        assert_eq!(range("width", 8, ..3), (0, 3));
+        assert_eq!(range("width", 8, 3..), (3, 8));
+        assert_eq!(range("width", 8, 8..), (8, 8));
+        assert_eq!(range("width", 8, (Bound::Excluded(2), Bound::Unbounded)), (3, 8));
+        assert_eq!(range("width", 8, (Bound::Excluded(2), Bound::Included(5))), (3, 6));
+
+        let rejected = |span: (Bound<usize>, Bound<usize>)| std::panic::catch_unwind(|| range("width", 8, span)).is_err();
+        assert!(rejected((Bound::Included(5), Bound::Excluded(2))));
+        assert!(rejected((Bound::Included(2), Bound::Excluded(9))));
+        assert!(rejected((Bound::Included(2), Bound::Included(8))));
+        assert!(rejected((Bound::Included(9), Bound::Unbounded)));
+        assert!(rejected((Bound::Excluded(usize::MAX), Bound::Unbounded)));
+        assert!(rejected((Bound::Unbounded, Bound::Included(usize::MAX))));
+    }
+
+    #[test]
+    #[should_panic(expected = "width range 5..2 is reversed")]
+    fn reversed_range_panics() {
+        #[allow(clippy::reversed_empty_ranges)]
+        range("width", 8, 5..2);
+    }
+
+    #[test]
+    #[should_panic(expected = "height range 2..9 extends out of bounds of 8")]
+    fn range_past_extent_panics() {
+        range("height", 8, 2..9);
+    }
+
+    #[test]
+    fn exact_pixel_count_is_accepted() {
+        check_pixels(4, [4]);
+        check_pixels(12, [4, 3]);
+        check_pixels(24, [4, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 pixels do not match 4 region")]
+    fn short_1d_slice_panics() {
+        check_pixels(3, [4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "11 pixels do not match 4x3 region")]
+    fn short_2d_slice_panics() {
+        check_pixels(11, [4, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "23 pixels do not match 4x3x2 region")]
+    fn short_3d_slice_panics() {
+        check_pixels(23, [4, 3, 2]);
+    }
+}
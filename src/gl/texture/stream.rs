@@ -0,0 +1,132 @@
+//! Streaming of texture uploads through pixel unpack buffers.
+//!
+//! Pixels are written into a mapped staging buffer on one frame and uploaded into the texture on a later one, so
+//! the transfer is performed by the driver without stalling the client. Every staging buffer is fenced after its
+//! upload and written again only once the fence signals.
+
+use std::collections::VecDeque;
+
+use crate::gl;
+use crate::glsl;
+use gl::buffer::{self, PixelUnpack};
+use gl::texture::{self, image, pixel, storage, Texture};
+
+/// Ring of `SLOTS` pixel unpack buffers, each holding pixels of a single upload.
+pub struct UnpackStream<Pixel, const SLOTS: usize = 2>
+where
+    Pixel: pixel::Pixel,
+{
+    buffers: [gl::Buffer<PixelUnpack, Pixel>; SLOTS],
+    fences: [glb::types::GLsync; SLOTS],
+    pending: VecDeque<usize>,
+    next: usize,
+}
+
+impl<Pixel, const SLOTS: usize> UnpackStream<Pixel, SLOTS>
+where
+    Pixel: pixel::Pixel,
+{
+    /// Create stream whose staging buffers hold `len` pixels each.
+    pub fn new(len: usize) -> Self {
+        let buffers = std::array::from_fn(|_| {
+            let mut buffer = gl::Buffer::create();
+            buffer.allocate::<(buffer::Stream, buffer::Draw)>(len);
+            buffer
+        });
+        Self {
+            buffers,
+            fences: [std::ptr::null(); SLOTS],
+            pending: VecDeque::with_capacity(SLOTS),
+            next: 0,
+        }
+    }
+
+    /// Map next staging buffer for pixels of an upload issued by later call to `upload_2d`.
+    ///
+    /// Blocks until the previous upload from the same staging buffer has completed. The mapped pixels are those of
+    /// that upload, or zeros if the staging buffer was not written yet.
+    pub fn write(&mut self) -> impl std::ops::DerefMut<Target = &mut [Pixel]> + '_ {
+        if self.pending.len() == SLOTS {
+            panic!("all {SLOTS} staging buffers are waiting for upload");
+        }
+        let slot = self.next;
+        self.next = (self.next + 1) % SLOTS;
+        self.wait(slot);
+        self.pending.push_back(slot);
+        self.buffers[slot].map_mut()
+    }
+
+    /// Upload pixels of the oldest written staging buffer into base level of `texture`.
+    ///
+    /// Returns `false` if no staging buffer was written since the last upload.
+    pub fn upload_2d<Channels, Target, Kind, InternalFormat, const MIPMAPS: bool>(
+        &mut self,
+        texture: &mut Texture<Target, Kind, InternalFormat, MIPMAPS>,
+        x_range: impl std::ops::RangeBounds<usize>,
+        y_range: impl std::ops::RangeBounds<usize>,
+    ) -> bool
+    where
+        Target: texture::Target<Dimensions = [usize; 2]> + texture::valid::ForSubImage2D,
+        Kind: storage::marker::Storage<Target = Target, Signature = storage::signature::Storage2D>,
+        InternalFormat: image::marker::Format,
+        Channels: pixel::channels::Channels + pixel::valid::ForImageBaseFormat<InternalFormat::BaseFormat>,
+        Pixel: pixel::Pixel<Components = Channels::Components, Type = InternalFormat::ComponentType> + pixel::valid::ForChannels<Channels>,
+        InternalFormat: image::marker::Uncompressed,
+        (Channels, <InternalFormat::Output as glsl::sampler::Output>::Kind): pixel::FormatToken,
+    {
+        let Some(slot) = self.pending.pop_front() else {
+            return false;
+        };
+        texture.sub_image_2d_from_buffer::<Channels, Pixel>(&self.buffers[slot], 0, x_range, y_range);
+        let fence;
+        gl::call! {
+            [panic]
+            unsafe {
+                fence = glb::FenceSync(glb::SYNC_GPU_COMMANDS_COMPLETE, 0);
+            }
+        }
+        self.fences[slot] = fence;
+        true
+    }
+
+    fn wait(&mut self, slot: usize) {
+        let fence = std::mem::replace(&mut self.fences[slot], std::ptr::null());
+        if fence.is_null() {
+            return;
+        }
+        loop {
+            let status;
+            gl::call! {
+                [panic]
+                unsafe {
+                    status = glb::ClientWaitSync(fence, glb::SYNC_FLUSH_COMMANDS_BIT, 1_000_000_000);
+                }
+            }
+            if status != glb::TIMEOUT_EXPIRED {
+                break;
+            }
+        }
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::DeleteSync(fence);
+            }
+        }
+    }
+}
+
+impl<Pixel, const SLOTS: usize> Drop for UnpackStream<Pixel, SLOTS>
+where
+    Pixel: pixel::Pixel,
+{
+    fn drop(&mut self) {
+        for &fence in self.fences.iter().filter(|fence| !fence.is_null()) {
+            gl::call! {
+                [panic]
+                unsafe {
+                    glb::DeleteSync(fence);
+                }
+            }
+        }
+    }
+}
@@ -14,20 +14,27 @@ pub mod _valid {
 
 /// Dimensionality of storage using given target
 pub trait Dimensionality {
-    type Dimensions: ext::Array;
+    type Dimensions: ext::Array + AsRef<[usize]> + AsMut<[usize]> + Copy + Default;
+    /// Number of leading dimensions halved with each mipmap level. Remaining ones count array layers.
+    const MIPMAPPED_AXES: usize;
 }
 
 macro_rules! impl_dimensionality {
-    ([$dim:literal] $($target:ty),+ $(,)?) => {
+    ([$dim:literal, $axes:literal] $($target:ty),+ $(,)?) => {
         $(
-            impl Dimensionality for $target { type Dimensions = [usize; $dim]; }
+            impl Dimensionality for $target {
+                type Dimensions = [usize; $dim];
+                const MIPMAPPED_AXES: usize = $axes;
+            }
         )+
     };
 }
 
-impl_dimensionality! { [1] D1, Buffer }
-impl_dimensionality! { [2] D2, Rectangle, D1Array, CubeMap, D2MultiSample }
-impl_dimensionality! { [3] D3, CubeMapArray, D2Array, D2MultiSampleArray }
+impl_dimensionality! { [1, 1] D1, Buffer }
+impl_dimensionality! { [2, 2] D2, Rectangle, CubeMap, D2MultiSample }
+impl_dimensionality! { [2, 1] D1Array }
+impl_dimensionality! { [3, 3] D3 }
+impl_dimensionality! { [3, 2] CubeMapArray, D2Array, D2MultiSampleArray }
 
 #[hi::marker]
 pub trait Target: gl::target::Target + Dimensionality { }
@@ -70,6 +77,34 @@ pub enum CubeMap { }
 
 pub type CubeMapArray = Array<CubeMap>;
 
+/// Face of a cube map. Faces are stored as consecutive layers in this order.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CubeFace {
+    PositiveX = glb::TEXTURE_CUBE_MAP_POSITIVE_X,
+    NegativeX = glb::TEXTURE_CUBE_MAP_NEGATIVE_X,
+    PositiveY = glb::TEXTURE_CUBE_MAP_POSITIVE_Y,
+    NegativeY = glb::TEXTURE_CUBE_MAP_NEGATIVE_Y,
+    PositiveZ = glb::TEXTURE_CUBE_MAP_POSITIVE_Z,
+    NegativeZ = glb::TEXTURE_CUBE_MAP_NEGATIVE_Z,
+}
+
+impl CubeFace {
+    pub const ALL: [Self; 6] = [
+        Self::PositiveX,
+        Self::NegativeX,
+        Self::PositiveY,
+        Self::NegativeY,
+        Self::PositiveZ,
+        Self::NegativeZ,
+    ];
+
+    /// Index of the layer storing the face.
+    pub const fn layer(self) -> usize {
+        (self as u32 - glb::TEXTURE_CUBE_MAP_POSITIVE_X) as usize
+    }
+}
+
 hi::denmark! { CubeMapArray as Target, storage::marker::Internal }
 
 pub struct MultiSample<T>(PhantomData<T>) where T: Target;
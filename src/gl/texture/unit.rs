@@ -0,0 +1,292 @@
+//! Texture units assigned at runtime.
+//!
+//! `TextureUnit` fixes its unit index at compile time, which does not fit texture counts known only at runtime.
+//! Here textures are bound to the first free units for the duration of a scope, usually single draw, and sampler
+//! uniforms are pointed at the assigned units by location or by name. Units are unbound when the scope ends.
+
+use crate::gl;
+use crate::glsl;
+use gl::program::Program;
+use gl::texture::{self, image, storage, Texture};
+
+/// Errors of unit assignment.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("all {0} texture units available for assignment are in use")]
+    Exhausted(usize),
+    #[error("program has no active uniform `{0}`")]
+    UnknownUniform(String),
+    #[error("uniform name {0:?} contains interior nul byte")]
+    InvalidName(String),
+    #[error("program has no active uniform at location {0}")]
+    UnknownLocation(usize),
+    #[error("uniform at location {location} has type {found:#x}, expected sampler type {expected:#x}")]
+    SamplerMismatch { location: usize, expected: u32, found: u32 },
+}
+
+/// Units assigned in current scope, tracked apart from binding them.
+struct Assignment {
+    first: usize,
+    limit: usize,
+    /// Names of textures, indexed by unit relative to `first`.
+    names: Vec<u32>,
+}
+
+impl Assignment {
+    /// Unit assigned to texture `name` and whether it was assigned just now.
+    fn assign(&mut self, name: u32) -> Result<(usize, bool), Error> {
+        if let Some(index) = self.names.iter().position(|&assigned| assigned == name) {
+            return Ok((self.first + index, false));
+        }
+        let unit = self.first + self.names.len();
+        if unit >= self.limit {
+            return Err(Error::Exhausted(self.limit - self.first));
+        }
+        self.names.push(name);
+        Ok((unit, true))
+    }
+
+    /// Release all units and return how many were assigned.
+    fn release(&mut self) -> usize {
+        let count = self.names.len();
+        self.names.clear();
+        count
+    }
+}
+
+/// Check that uniform at `location` is sampler of type `expected`. Uniforms are given as their type, location and array size.
+fn check_sampler(location: usize, expected: u32, uniforms: impl IntoIterator<Item = [i32; 3]>) -> Result<(), Error> {
+    let [found, ..] = uniforms
+        .into_iter()
+        .find(|&[_, first, size]| first >= 0 && (first as usize..first as usize + size as usize).contains(&location))
+        .ok_or(Error::UnknownLocation(location))?;
+    if found as u32 != expected {
+        return Err(Error::SamplerMismatch { location, expected, found: found as _ });
+    }
+    Ok(())
+}
+
+/// Type, location and array size of active uniforms of program `name`.
+fn uniforms(name: u32) -> Vec<[i32; 3]> {
+    const PROPERTIES: [u32; 3] = [glb::TYPE, glb::LOCATION, glb::ARRAY_SIZE];
+    let mut count = 0;
+    gl::call! {
+        [panic]
+        unsafe {
+            glb::GetProgramInterfaceiv(name, glb::UNIFORM, glb::ACTIVE_RESOURCES, &mut count);
+        }
+    }
+    let mut uniforms = vec![[0; 3]; count as usize];
+    for (index, values) in uniforms.iter_mut().enumerate() {
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetProgramResourceiv(name, glb::UNIFORM, index as _, 3, PROPERTIES.as_ptr(), 3, std::ptr::null_mut(), values.as_mut_ptr());
+            }
+        }
+    }
+    uniforms
+}
+
+/// Assigns texture units starting at `first` up to the implementation limit of combined texture image units.
+///
+/// Units below `first` are left for textures bound with `TextureUnit`.
+pub struct TextureUnitAllocator {
+    assignment: Assignment,
+}
+
+impl TextureUnitAllocator {
+    /// Allocator assigning all texture units.
+    pub fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Allocator assigning units from `first` onwards.
+    pub fn starting_at(first: usize) -> Self {
+        let mut limit = 0;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::GetIntegerv(glb::MAX_COMBINED_TEXTURE_IMAGE_UNITS, &mut limit);
+            }
+        }
+        let limit = limit as usize;
+        if first > limit {
+            panic!("first unit {first} is out of bounds of {limit} texture units");
+        }
+        Self { assignment: Assignment { first, limit, names: Vec::new() } }
+    }
+
+    /// Begin scope whose textures stay bound until it is dropped.
+    pub fn scope(&mut self) -> UnitScope<'_> {
+        UnitScope { allocator: self }
+    }
+}
+
+impl Default for TextureUnitAllocator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Textures bound to units assigned by `TextureUnitAllocator` for the lifetime `'a`.
+pub struct UnitScope<'a> {
+    allocator: &'a mut TextureUnitAllocator,
+}
+
+impl<'a> UnitScope<'a> {
+    /// Bind `texture` to free unit and return its index. Texture bound earlier in the scope keeps its unit.
+    pub fn bind<T, K, InternalFormat, const MIPMAPS: bool>(&mut self, texture: &'a Texture<T, K, InternalFormat, MIPMAPS>) -> Result<usize, Error>
+    where
+        T: texture::Target,
+        K: storage::marker::Kind<Target = T>,
+        InternalFormat: image::marker::Format,
+    {
+        let name = texture.object.name();
+        let (unit, assigned) = self.allocator.assignment.assign(name)?;
+        if assigned {
+            gl::call! {
+                [panic]
+                unsafe {
+                    glb::BindTextureUnit(unit as _, name);
+                }
+            }
+        }
+        Ok(unit)
+    }
+
+    /// Bind `texture` and point sampler uniform at `location` of `program` to its unit.
+    ///
+    /// Uniform must be sampler which reads textures of given target and format.
+    pub fn bind_to_location<T, K, InternalFormat, const MIPMAPS: bool, Ins, Outs, Unis, Res>(
+        &mut self,
+        texture: &'a Texture<T, K, InternalFormat, MIPMAPS>,
+        program: &Program<Ins, Outs, Unis, Res>,
+        location: usize,
+    ) -> Result<usize, Error>
+    where
+        T: texture::Target,
+        K: storage::marker::Kind<Target = T>,
+        InternalFormat: image::marker::Format,
+        glsl::GSampler<<InternalFormat::Sampling as image::marker::Sampling>::Target<T>, InternalFormat::Output>: glsl::sampler::Type,
+        Ins: glsl::Parameters<glsl::storage::In>,
+        Outs: glsl::Parameters<glsl::storage::Out>,
+        Unis: gl::uniform::bounds::Declarations,
+    {
+        let expected = <glsl::GSampler<<InternalFormat::Sampling as image::marker::Sampling>::Target<T>, InternalFormat::Output> as glsl::sampler::Type>::ID;
+        check_sampler(location, expected, uniforms(program.name()))?;
+        let unit = self.bind(texture)?;
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::ProgramUniform1i(program.name(), location as _, unit as _);
+            }
+        }
+        Ok(unit)
+    }
+
+    /// Bind `texture` and point sampler uniform called `name` of `program` to its unit.
+    ///
+    /// Uniform must be sampler which reads textures of given target and format.
+    pub fn bind_to_uniform<T, K, InternalFormat, const MIPMAPS: bool, Ins, Outs, Unis, Res>(
+        &mut self,
+        texture: &'a Texture<T, K, InternalFormat, MIPMAPS>,
+        program: &Program<Ins, Outs, Unis, Res>,
+        name: &str,
+    ) -> Result<usize, Error>
+    where
+        T: texture::Target,
+        K: storage::marker::Kind<Target = T>,
+        InternalFormat: image::marker::Format,
+        glsl::GSampler<<InternalFormat::Sampling as image::marker::Sampling>::Target<T>, InternalFormat::Output>: glsl::sampler::Type,
+        Ins: glsl::Parameters<glsl::storage::In>,
+        Outs: glsl::Parameters<glsl::storage::Out>,
+        Unis: gl::uniform::bounds::Declarations,
+    {
+        let c_name = std::ffi::CString::new(name).map_err(|_| Error::InvalidName(name.to_owned()))?;
+        let location;
+        gl::call! {
+            [panic]
+            unsafe {
+                location = glb::GetUniformLocation(program.name(), c_name.as_ptr());
+            }
+        }
+        if location < 0 {
+            return Err(Error::UnknownUniform(name.to_owned()));
+        }
+        self.bind_to_location(texture, program, location as _)
+    }
+}
+
+impl Drop for UnitScope<'_> {
+    fn drop(&mut self) {
+        let count = self.allocator.assignment.release();
+        if count == 0 {
+            return;
+        }
+        gl::call! {
+            [panic]
+            unsafe {
+                glb::BindTextures(self.allocator.assignment.first as _, count as _, std::ptr::null());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assignment(first: usize, limit: usize) -> Assignment {
+        Assignment { first, limit, names: Vec::new() }
+    }
+
+    #[test]
+    fn textures_are_assigned_consecutive_units_from_first() {
+        let mut assignment = assignment(4, 16);
+        assert_eq!(assignment.assign(7).unwrap(), (4, true));
+        assert_eq!(assignment.assign(3).unwrap(), (5, true));
+        assert_eq!(assignment.assign(7).unwrap(), (4, false));
+        assert_eq!(assignment.assign(9).unwrap(), (6, true));
+    }
+
+    #[test]
+    fn assignment_past_limit_is_exhausted() {
+        assert!(matches!(assignment(16, 16).assign(1), Err(Error::Exhausted(0))));
+
+        let mut assignment = assignment(14, 16);
+        assignment.assign(1).unwrap();
+        assignment.assign(2).unwrap();
+        assert!(matches!(assignment.assign(3), Err(Error::Exhausted(2))));
+        assert_eq!(assignment.assign(2).unwrap(), (15, false));
+    }
+
+    #[test]
+    fn released_units_are_assigned_again() {
+        let mut assignment = assignment(14, 16);
+        assignment.assign(1).unwrap();
+        assignment.assign(2).unwrap();
+        assert_eq!(assignment.release(), 2);
+        assert_eq!(assignment.release(), 0);
+        assert_eq!(assignment.assign(3).unwrap(), (14, true));
+        assert_eq!(assignment.assign(1).unwrap(), (15, true));
+    }
+
+    #[test]
+    fn sampler_uniform_is_checked_by_location() {
+        let uniforms = [
+            [glb::FLOAT_MAT4 as i32, 0, 1],
+            [glb::FLOAT as i32, -1, 1],
+            [glb::SAMPLER_2D as i32, 1, 1],
+            [glb::UNSIGNED_INT_SAMPLER_2D as i32, 2, 3],
+        ];
+        assert!(check_sampler(1, glb::SAMPLER_2D, uniforms).is_ok());
+        assert!(check_sampler(3, glb::UNSIGNED_INT_SAMPLER_2D, uniforms).is_ok());
+        assert!(matches!(
+            check_sampler(4, glb::SAMPLER_2D, uniforms),
+            Err(Error::SamplerMismatch { location: 4, expected: glb::SAMPLER_2D, found: glb::UNSIGNED_INT_SAMPLER_2D })
+        ));
+        assert!(matches!(check_sampler(0, glb::SAMPLER_2D, uniforms), Err(Error::SamplerMismatch { .. })));
+        assert!(matches!(check_sampler(5, glb::SAMPLER_2D, uniforms), Err(Error::UnknownLocation(5))));
+    }
+}
@@ -5,3 +5,124 @@ pub trait TextureDim { }
 hi::denmark! { Const<1> as TextureDim }
 hi::denmark! { Const<2> as TextureDim }
 hi::denmark! { Const<3> as TextureDim }
+
+/// Targets whose images are addressed as a single 2D plane.
+#[diagnostic::on_unimplemented(message = "`{Self}` images are not a single plane", note = "cube map faces are written with `sub_image_face`")]
+#[hi::marker]
+pub trait ForSubImage2D: super::Target { }
+
+hi::denmark! { super::target::D2 as ForSubImage2D }
+hi::denmark! { super::target::D1Array as ForSubImage2D }
+hi::denmark! { super::target::Rectangle as ForSubImage2D }
+
+/// Targets whose images can be written and read back with pixel transfers.
+#[diagnostic::on_unimplemented(message = "`{Self}` images cannot be transferred to or from client memory", note = "multisample images are only written by rendering")]
+#[hi::marker]
+pub trait ForPixelTransfer: super::Target { }
+
+hi::denmark! { super::target::D1 as ForPixelTransfer }
+hi::denmark! { super::target::D2 as ForPixelTransfer }
+hi::denmark! { super::target::D3 as ForPixelTransfer }
+hi::denmark! { super::target::D1Array as ForPixelTransfer }
+hi::denmark! { super::target::D2Array as ForPixelTransfer }
+hi::denmark! { super::target::Rectangle as ForPixelTransfer }
+hi::denmark! { super::target::CubeMap as ForPixelTransfer }
+hi::denmark! { super::target::CubeMapArray as ForPixelTransfer }
+
+/// Targets of views that can be created over texture of target `Original`.
+#[diagnostic::on_unimplemented(message = "`{Self}` view cannot be created over `{Original}` texture")]
+pub trait ForView<Original: super::Target>: super::Target { }
+
+macro_rules! impl_for_view {
+    ($original:ty => $($view:ty),+) => {
+        $(impl ForView<$original> for $view { })+
+    };
+}
+
+impl_for_view! { super::target::D1 => super::target::D1, super::target::D1Array }
+impl_for_view! { super::target::D1Array => super::target::D1, super::target::D1Array }
+impl_for_view! { super::target::D2 => super::target::D2, super::target::D2Array }
+impl_for_view! { super::target::D3 => super::target::D3 }
+impl_for_view! { super::target::Rectangle => super::target::Rectangle }
+impl_for_view! { super::target::D2Array => super::target::D2, super::target::D2Array, super::target::CubeMap, super::target::CubeMapArray }
+impl_for_view! { super::target::CubeMap => super::target::D2, super::target::D2Array, super::target::CubeMap, super::target::CubeMapArray }
+impl_for_view! { super::target::CubeMapArray => super::target::D2, super::target::D2Array, super::target::CubeMap, super::target::CubeMapArray }
+impl_for_view! { super::target::D2MultiSample => super::target::D2MultiSample, super::target::D2MultiSampleArray }
+impl_for_view! { super::target::D2MultiSampleArray => super::target::D2MultiSample, super::target::D2MultiSampleArray }
+
+/// Targets whose storage can contain more than one mipmap level.
+#[diagnostic::on_unimplemented(message = "`{Self}` textures cannot contain mipmaps")]
+#[hi::marker]
+pub trait ForMipmaps: super::Target { }
+
+hi::denmark! { super::target::D1 as ForMipmaps }
+hi::denmark! { super::target::D2 as ForMipmaps }
+hi::denmark! { super::target::D3 as ForMipmaps }
+hi::denmark! { super::target::D1Array as ForMipmaps }
+hi::denmark! { super::target::D2Array as ForMipmaps }
+hi::denmark! { super::target::CubeMap as ForMipmaps }
+hi::denmark! { super::target::CubeMapArray as ForMipmaps }
+
+/// Targets whose images can be copied with `glCopyImageSubData`.
+#[diagnostic::on_unimplemented(message = "`{Self}` images cannot be copied", note = "buffer textures are copied through their buffer")]
+#[hi::marker]
+pub trait ForCopy: super::Target { }
+
+hi::denmark! { super::target::D1 as ForCopy }
+hi::denmark! { super::target::D2 as ForCopy }
+hi::denmark! { super::target::D3 as ForCopy }
+hi::denmark! { super::target::D1Array as ForCopy }
+hi::denmark! { super::target::D2Array as ForCopy }
+hi::denmark! { super::target::Rectangle as ForCopy }
+hi::denmark! { super::target::CubeMap as ForCopy }
+hi::denmark! { super::target::CubeMapArray as ForCopy }
+hi::denmark! { super::target::D2MultiSample as ForCopy }
+hi::denmark! { super::target::D2MultiSampleArray as ForCopy }
+
+/// Internal formats of buffer textures.
+#[diagnostic::on_unimplemented(message = "`{Self}` cannot be internal format of buffer texture", note = "buffer textures take 1, 2 or 4 component formats with 8, 16 or 32 bit components and 3 component formats with 32 bit components")]
+#[hi::marker]
+pub trait ForBufferTexture: super::image::marker::Format { }
+
+mod buffer_texture {
+    use super::ForBufferTexture;
+    use crate::gl::types::float16;
+    use crate::gl::texture::image::{Format, format::{components::*, ty::*}};
+
+    hi::denmark! { Format<R, u8>         as ForBufferTexture }
+    hi::denmark! { Format<R, u16>        as ForBufferTexture }
+    hi::denmark! { Format<R, float16, F> as ForBufferTexture }
+    hi::denmark! { Format<R, f32, F>     as ForBufferTexture }
+    hi::denmark! { Format<R, i8, I>      as ForBufferTexture }
+    hi::denmark! { Format<R, i16, I>     as ForBufferTexture }
+    hi::denmark! { Format<R, i32, I>     as ForBufferTexture }
+    hi::denmark! { Format<R, u8, UI>     as ForBufferTexture }
+    hi::denmark! { Format<R, u16, UI>    as ForBufferTexture }
+    hi::denmark! { Format<R, u32, UI>    as ForBufferTexture }
+
+    hi::denmark! { Format<RG, u8>         as ForBufferTexture }
+    hi::denmark! { Format<RG, u16>        as ForBufferTexture }
+    hi::denmark! { Format<RG, float16, F> as ForBufferTexture }
+    hi::denmark! { Format<RG, f32, F>     as ForBufferTexture }
+    hi::denmark! { Format<RG, i8, I>      as ForBufferTexture }
+    hi::denmark! { Format<RG, i16, I>     as ForBufferTexture }
+    hi::denmark! { Format<RG, i32, I>     as ForBufferTexture }
+    hi::denmark! { Format<RG, u8, UI>     as ForBufferTexture }
+    hi::denmark! { Format<RG, u16, UI>    as ForBufferTexture }
+    hi::denmark! { Format<RG, u32, UI>    as ForBufferTexture }
+
+    hi::denmark! { Format<RGB, f32, F>  as ForBufferTexture }
+    hi::denmark! { Format<RGB, i32, I>  as ForBufferTexture }
+    hi::denmark! { Format<RGB, u32, UI> as ForBufferTexture }
+
+    hi::denmark! { Format<RGBA, u8>         as ForBufferTexture }
+    hi::denmark! { Format<RGBA, u16>        as ForBufferTexture }
+    hi::denmark! { Format<RGBA, float16, F> as ForBufferTexture }
+    hi::denmark! { Format<RGBA, f32, F>     as ForBufferTexture }
+    hi::denmark! { Format<RGBA, i8, I>      as ForBufferTexture }
+    hi::denmark! { Format<RGBA, i16, I>     as ForBufferTexture }
+    hi::denmark! { Format<RGBA, i32, I>     as ForBufferTexture }
+    hi::denmark! { Format<RGBA, u8, UI>     as ForBufferTexture }
+    hi::denmark! { Format<RGBA, u16, UI>    as ForBufferTexture }
+    hi::denmark! { Format<RGBA, u32, UI>    as ForBufferTexture }
+}
@@ -23,14 +23,91 @@ impl u31 {
 #[allow(non_camel_case_types)]
 pub struct irgb10a2(u32);
 
+/// Unsigned red, green, blue and alpha packed into 10, 10, 10 and 2 bits, red occupying the least significant bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 #[allow(non_camel_case_types)]
 pub struct urgb10a2(u32);
 
+impl urgb10a2 {
+    pub const fn new(red: u32, green: u32, blue: u32, alpha: u32) -> Self {
+        if red >= 1 << 10 || green >= 1 << 10 || blue >= 1 << 10 || alpha >= 1 << 2 {
+            panic!("component does not fit into its bits")
+        }
+        Self(red | green << 10 | blue << 20 | alpha << 30)
+    }
+
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
+/// Unsigned floats packed into 11, 11 and 10 bits, red occupying the least significant bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(transparent)]
 #[allow(non_camel_case_types)]
 pub struct u10f10f11f(u32);
 
+impl u10f10f11f {
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
+/// Unsigned red, green and blue packed into 5, 6 and 5 bits, red occupying the most significant bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct urgb565(u16);
+
+impl urgb565 {
+    pub const fn new(red: u16, green: u16, blue: u16) -> Self {
+        if red >= 1 << 5 || green >= 1 << 6 || blue >= 1 << 5 {
+            panic!("component does not fit into its bits")
+        }
+        Self(red << 11 | green << 5 | blue)
+    }
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+}
+
+/// Unsigned normalized depth in the most significant 24 bits and stencil index in the least significant 8 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u24u8(u32);
+
+impl u24u8 {
+    pub const fn new(depth: u32, stencil: u8) -> Self {
+        if depth >= 1 << 24 {
+            panic!("depth does not fit into 24 bits")
+        }
+        Self(depth << 8 | stencil as u32)
+    }
+
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
 #[repr(transparent)]
 #[allow(non_camel_case_types)]
 pub struct fixed16(u16);
@@ -55,6 +132,29 @@ pub unsafe trait Type {
 
 pub trait Packed: Type { }
 
+/// 32-bit float depth followed by 24 unused bits and stencil index in the least significant 8 bits of the next word.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct f32u24u8 {
+    depth: f32,
+    stencil: u32,
+}
+
+impl f32u24u8 {
+    pub const fn new(depth: f32, stencil: u8) -> Self {
+        Self { depth, stencil: stencil as u32 }
+    }
+
+    pub const fn depth(self) -> f32 {
+        self.depth
+    }
+
+    pub const fn stencil(self) -> u8 {
+        self.stencil as u8
+    }
+}
+
 unsafe impl Type for u8  { const ID: u32 = glb::UNSIGNED_BYTE   ; type Kind = Integer; }
 unsafe impl Type for u16 { const ID: u32 = glb::UNSIGNED_SHORT  ; type Kind = Integer; }
 unsafe impl Type for u32 { const ID: u32 = glb::UNSIGNED_INT    ; type Kind = Integer; }
@@ -67,5 +167,47 @@ unsafe impl Type for float16 { const ID: u32 = glb::HALF_FLOAT  ; type Kind = Fl
 unsafe impl Type for f32     { const ID: u32 = glb::FLOAT       ; type Kind = Float; }
 unsafe impl Type for f64     { const ID: u32 = glb::DOUBLE      ; type Kind = Float; }
 
+unsafe impl Type for urgb565    { const ID: u32 = glb::UNSIGNED_SHORT_5_6_5         ; type Kind = Integer; }
+unsafe impl Type for urgb10a2   { const ID: u32 = glb::UNSIGNED_INT_2_10_10_10_REV  ; type Kind = Integer; }
+unsafe impl Type for u10f10f11f { const ID: u32 = glb::UNSIGNED_INT_10F_11F_11F_REV ; type Kind = Float; }
+unsafe impl Type for u24u8      { const ID: u32 = glb::UNSIGNED_INT_24_8            ; type Kind = Integer; }
+unsafe impl Type for f32u24u8   { const ID: u32 = glb::FLOAT_32_UNSIGNED_INT_24_8_REV ; type Kind = Float; }
+
+impl Packed for urgb565 { }
+impl Packed for urgb10a2 { }
+impl Packed for u10f10f11f { }
+impl Packed for u24u8 { }
+impl Packed for f32u24u8 { }
+
 #[repr(transparent)]
 pub struct Normalized<I>(I);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_components_occupy_their_bits() {
+        assert_eq!(urgb565::new(0b11111, 0, 0).to_bits(), 0b11111_000000_00000);
+        assert_eq!(urgb565::new(0, 0b111111, 0).to_bits(), 0b00000_111111_00000);
+        assert_eq!(urgb565::new(0, 0, 0b11111).to_bits(), 0b00000_000000_11111);
+
+        assert_eq!(urgb10a2::new(0x3FF, 0, 0, 0).to_bits(), 0x0000_03FF);
+        assert_eq!(urgb10a2::new(0, 0x3FF, 0, 0).to_bits(), 0x000F_FC00);
+        assert_eq!(urgb10a2::new(0, 0, 0x3FF, 0).to_bits(), 0x3FF0_0000);
+        assert_eq!(urgb10a2::new(0, 0, 0, 0b11).to_bits(), 0xC000_0000);
+
+        assert_eq!(u24u8::new(0xFF_FFFF, 0).to_bits(), 0xFFFF_FF00);
+        assert_eq!(u24u8::new(0, 0xFF).to_bits(), 0x0000_00FF);
+
+        let depth_stencil = f32u24u8::new(0.5, 7);
+        assert_eq!((depth_stencil.depth(), depth_stencil.stencil()), (0.5, 7));
+        assert_eq!(std::mem::size_of::<f32u24u8>(), 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn overflowing_component_panics() {
+        urgb565::new(0, 1 << 6, 0);
+    }
+}
@@ -15,6 +15,7 @@ use glsl::valid;
 use gl::texture;
 
 pub use glsl::sampler::*;
+pub use glsl::image::*;
 
 /// A glsl type.
 pub trait Type {
@@ -271,14 +272,32 @@ use bounds::TransparentType;
 
 impl<T, O> Type for GSampler<T, O>
 where
-    T: texture::Target,
+    T: sampler::Target,
     O: sampler::Output,
 {
     type Group = valid::Opaque;
 }
 
 impl<T, O> bounds::OpaqueType for GSampler<T, O>
+where
+    T: sampler::Target,
+    O: sampler::Output,
+{ }
+
+impl<T, O, F, A> Type for GImage<T, O, F, A>
+where
+    T: texture::Target,
+    O: sampler::Output,
+    F: FormatQualifier<Output = O>,
+    A: memory::Access,
+{
+    type Group = valid::Opaque;
+}
+
+impl<T, O, F, A> bounds::OpaqueType for GImage<T, O, F, A>
 where
     T: texture::Target,
     O: sampler::Output,
+    F: FormatQualifier<Output = O>,
+    A: memory::Access,
 { }
@@ -0,0 +1,163 @@
+use std::marker::PhantomData;
+
+use crate::gl::texture;
+use crate::gl::types::float16;
+use crate::glsl::sampler::Output;
+use texture::image::format::{self, components::{R, RG, RGBA}};
+use texture::image::marker::Format;
+
+/// Memory qualifiers of image variables.
+pub mod memory {
+    use std::marker::PhantomData;
+
+    /// Memory qualifier, `Base` being the access it permits and `ID` the matching access of image unit.
+    pub trait Access {
+        type Base: Access;
+        const ID: u32;
+    }
+
+    /// `readonly` images.
+    pub enum ReadOnly { }
+    /// `writeonly` images.
+    pub enum WriteOnly { }
+    /// Images without access qualifier.
+    pub enum ReadWrite { }
+
+    impl Access for ReadOnly { type Base = Self; const ID: u32 = glb::READ_ONLY; }
+    impl Access for WriteOnly { type Base = Self; const ID: u32 = glb::WRITE_ONLY; }
+    impl Access for ReadWrite { type Base = Self; const ID: u32 = glb::READ_WRITE; }
+
+    /// `coherent` image with access `A`.
+    pub struct Coherent<A>(PhantomData<A>) where A: Access;
+    /// `volatile` image with access `A`.
+    pub struct Volatile<A>(PhantomData<A>) where A: Access;
+    /// `restrict` image with access `A`.
+    pub struct Restrict<A>(PhantomData<A>) where A: Access;
+
+    impl<A> Access for Coherent<A> where A: Access { type Base = A::Base; const ID: u32 = A::ID; }
+    impl<A> Access for Volatile<A> where A: Access { type Base = A::Base; const ID: u32 = A::ID; }
+    impl<A> Access for Restrict<A> where A: Access { type Base = A::Base; const ID: u32 = A::ID; }
+
+    /// Access of image unit which permits image variable with access `A`.
+    #[diagnostic::on_unimplemented(message = "image unit with `{Self}` access cannot serve `{A}` image variables")]
+    pub trait Grants<A: Access>: Access { }
+
+    impl Grants<ReadOnly> for ReadOnly { }
+    impl Grants<WriteOnly> for WriteOnly { }
+    impl Grants<ReadOnly> for ReadWrite { }
+    impl Grants<WriteOnly> for ReadWrite { }
+    impl Grants<ReadWrite> for ReadWrite { }
+}
+
+/// Internal formats that can be given as format layout qualifier of image variable.
+#[diagnostic::on_unimplemented(message = "`{Self}` is not a valid image format layout qualifier")]
+#[hi::marker]
+pub trait FormatQualifier: Format { }
+
+hi::denmark! { texture::image::Format<RGBA, f32, format::F> as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, float16, format::F> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, f32, format::F> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, float16, format::F> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, f32, format::F> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, float16, format::F> as FormatQualifier }
+hi::denmark! { format::R11F_G11F_B10F as FormatQualifier }
+
+hi::denmark! { texture::image::Format<RGBA, u16> as FormatQualifier }
+hi::denmark! { format::RGB10_A2 as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, u8> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, u16> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, u8> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, u16> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, u8> as FormatQualifier }
+
+hi::denmark! { texture::image::Format<RGBA, i16, format::SNorm> as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, i8, format::SNorm> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, i16, format::SNorm> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, i8, format::SNorm> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, i16, format::SNorm> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, i8, format::SNorm> as FormatQualifier }
+
+hi::denmark! { texture::image::Format<RGBA, i32, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, i16, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, i8, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, i32, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, i16, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, i8, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, i32, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, i16, format::I> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, i8, format::I> as FormatQualifier }
+
+hi::denmark! { texture::image::Format<RGBA, u32, format::UI> as FormatQualifier }
+hi::denmark! { format::RGB10_A2UI as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, u16, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<RGBA, u8, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, u32, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, u16, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<RG, u8, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, u32, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, u16, format::UI> as FormatQualifier }
+hi::denmark! { texture::image::Format<R, u8, format::UI> as FormatQualifier }
+
+/// Image variable of target `T` declared with format layout qualifier `F` and memory qualifier `A`.
+/// `O` is the kind of values loaded from and stored into the image, which distinguishes `image`, `iimage` and `uimage`.
+pub struct GImage<T, O, F, A = memory::ReadWrite>(PhantomData<(T, O, F, A)>)
+where
+    T: texture::Target,
+    O: Output,
+    F: FormatQualifier<Output = O>,
+    A: memory::Access,
+;
+
+impl<T, O, F, A> Default for GImage<T, O, F, A>
+where
+    T: texture::Target,
+    O: Output,
+    F: FormatQualifier<Output = O>,
+    A: memory::Access,
+{
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+type Image<Target, F, A> = GImage<Target, f32, F, A>;
+
+pub type Image1D<F, A = memory::ReadWrite>        = Image<texture::target::D1, F, A>;
+pub type Image1DArray<F, A = memory::ReadWrite>   = Image<texture::target::D1Array, F, A>;
+pub type Image2D<F, A = memory::ReadWrite>        = Image<texture::target::D2, F, A>;
+pub type Image2DArray<F, A = memory::ReadWrite>   = Image<texture::target::D2Array, F, A>;
+pub type Image2DMS<F, A = memory::ReadWrite>      = Image<texture::target::D2MultiSample, F, A>;
+pub type Image2DMSArray<F, A = memory::ReadWrite> = Image<texture::target::D2MultiSampleArray, F, A>;
+pub type Image2DRect<F, A = memory::ReadWrite>    = Image<texture::target::Rectangle, F, A>;
+pub type Image3D<F, A = memory::ReadWrite>        = Image<texture::target::D3, F, A>;
+pub type ImageCube<F, A = memory::ReadWrite>      = Image<texture::target::CubeMap, F, A>;
+pub type ImageCubeArray<F, A = memory::ReadWrite> = Image<texture::target::CubeMapArray, F, A>;
+pub type ImageBuffer<F, A = memory::ReadWrite>    = Image<texture::target::Buffer, F, A>;
+
+type IImage<Target, F, A> = GImage<Target, i32, F, A>;
+
+pub type IImage1D<F, A = memory::ReadWrite>        = IImage<texture::target::D1, F, A>;
+pub type IImage1DArray<F, A = memory::ReadWrite>   = IImage<texture::target::D1Array, F, A>;
+pub type IImage2D<F, A = memory::ReadWrite>        = IImage<texture::target::D2, F, A>;
+pub type IImage2DArray<F, A = memory::ReadWrite>   = IImage<texture::target::D2Array, F, A>;
+pub type IImage2DMS<F, A = memory::ReadWrite>      = IImage<texture::target::D2MultiSample, F, A>;
+pub type IImage2DMSArray<F, A = memory::ReadWrite> = IImage<texture::target::D2MultiSampleArray, F, A>;
+pub type IImage2DRect<F, A = memory::ReadWrite>    = IImage<texture::target::Rectangle, F, A>;
+pub type IImage3D<F, A = memory::ReadWrite>        = IImage<texture::target::D3, F, A>;
+pub type IImageCube<F, A = memory::ReadWrite>      = IImage<texture::target::CubeMap, F, A>;
+pub type IImageCubeArray<F, A = memory::ReadWrite> = IImage<texture::target::CubeMapArray, F, A>;
+pub type IImageBuffer<F, A = memory::ReadWrite>    = IImage<texture::target::Buffer, F, A>;
+
+type UImage<Target, F, A> = GImage<Target, u32, F, A>;
+
+pub type UImage1D<F, A = memory::ReadWrite>        = UImage<texture::target::D1, F, A>;
+pub type UImage1DArray<F, A = memory::ReadWrite>   = UImage<texture::target::D1Array, F, A>;
+pub type UImage2D<F, A = memory::ReadWrite>        = UImage<texture::target::D2, F, A>;
+pub type UImage2DArray<F, A = memory::ReadWrite>   = UImage<texture::target::D2Array, F, A>;
+pub type UImage2DMS<F, A = memory::ReadWrite>      = UImage<texture::target::D2MultiSample, F, A>;
+pub type UImage2DMSArray<F, A = memory::ReadWrite> = UImage<texture::target::D2MultiSampleArray, F, A>;
+pub type UImage2DRect<F, A = memory::ReadWrite>    = UImage<texture::target::Rectangle, F, A>;
+pub type UImage3D<F, A = memory::ReadWrite>        = UImage<texture::target::D3, F, A>;
+pub type UImageCube<F, A = memory::ReadWrite>      = UImage<texture::target::CubeMap, F, A>;
+pub type UImageCubeArray<F, A = memory::ReadWrite> = UImage<texture::target::CubeMapArray, F, A>;
+pub type UImageBuffer<F, A = memory::ReadWrite>    = UImage<texture::target::Buffer, F, A>;
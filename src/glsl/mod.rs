@@ -4,6 +4,7 @@ mod glsl;
 pub mod location;
 pub mod parameters;
 pub mod sampler;
+pub mod image;
 pub mod uniform;
 pub mod qualifier;
 pub mod bounds;
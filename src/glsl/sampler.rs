@@ -11,15 +11,21 @@ impl Output for u32 { type Kind = gl::types::Integer; }
 
 pub struct Shadow<Target>(PhantomData<Target>) where Target: texture::Target;
 
-pub struct GSampler<Target, O>(PhantomData<(Target, O)>)
+/// Targets samplers can be declared for: texture targets and their shadow variants.
+pub trait Target { }
+
+impl<T> Target for T where T: texture::Target { }
+impl<T> Target for Shadow<T> where T: texture::Target { }
+
+pub struct GSampler<T, O>(PhantomData<(T, O)>)
 where
-    Target: texture::Target,
+    T: Target,
     O: Output
 ;
 
-impl<Target, O> Default for GSampler<Target, O>
+impl<T, O> Default for GSampler<T, O>
 where
-    Target: texture::Target,
+    T: Target,
     O: Output
 {
     fn default() -> Self {
@@ -98,3 +104,67 @@ where
 { }
 
 
+
+/// Sampler types GLSL declares, identified by OpenGL type reported by program introspection.
+pub trait Type {
+    const ID: u32;
+}
+
+macro_rules! impl_type {
+    ($($sampler:ty as $gl_type_ident:ident),+ $(,)?) => {
+        $(
+            impl Type for $sampler {
+                const ID: u32 = glb::$gl_type_ident;
+            }
+        )+
+    };
+}
+
+impl_type! {
+    Sampler1D                as SAMPLER_1D,
+    Sampler1DShadow          as SAMPLER_1D_SHADOW,
+    Sampler1DArray           as SAMPLER_1D_ARRAY,
+    Sampler1DArrayShadow     as SAMPLER_1D_ARRAY_SHADOW,
+    Sampler2D                as SAMPLER_2D,
+    Sampler2DShadow          as SAMPLER_2D_SHADOW,
+    Sampler2DArray           as SAMPLER_2D_ARRAY,
+    Sampler2DArrayShadow     as SAMPLER_2D_ARRAY_SHADOW,
+    Sampler3D                as SAMPLER_3D,
+    Sampler2DMS              as SAMPLER_2D_MULTISAMPLE,
+    Sampler2DMSArray         as SAMPLER_2D_MULTISAMPLE_ARRAY,
+    Sampler2DRect            as SAMPLER_2D_RECT,
+    Sampler2DRectShadow      as SAMPLER_2D_RECT_SHADOW,
+    Sampler2DCube            as SAMPLER_CUBE,
+    Sampler2DCubeShadow      as SAMPLER_CUBE_SHADOW,
+    Sampler2DCubeArray       as SAMPLER_CUBE_MAP_ARRAY,
+    Sampler2DCubeArrayShadow as SAMPLER_CUBE_MAP_ARRAY_SHADOW,
+    SamplerBuffer            as SAMPLER_BUFFER,
+}
+
+impl_type! {
+    ISampler1D          as INT_SAMPLER_1D,
+    ISampler1DArray     as INT_SAMPLER_1D_ARRAY,
+    ISampler2D          as INT_SAMPLER_2D,
+    ISampler2DArray     as INT_SAMPLER_2D_ARRAY,
+    ISampler3D          as INT_SAMPLER_3D,
+    ISampler2DMS        as INT_SAMPLER_2D_MULTISAMPLE,
+    ISampler2DMSArray   as INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+    ISampler2DRect      as INT_SAMPLER_2D_RECT,
+    ISampler2DCube      as INT_SAMPLER_CUBE,
+    ISampler2DCubeArray as INT_SAMPLER_CUBE_MAP_ARRAY,
+    ISamplerBuffer      as INT_SAMPLER_BUFFER,
+}
+
+impl_type! {
+    USampler1D          as UNSIGNED_INT_SAMPLER_1D,
+    USampler1DArray     as UNSIGNED_INT_SAMPLER_1D_ARRAY,
+    USampler2D          as UNSIGNED_INT_SAMPLER_2D,
+    USampler2DArray     as UNSIGNED_INT_SAMPLER_2D_ARRAY,
+    USampler3D          as UNSIGNED_INT_SAMPLER_3D,
+    USampler2DMS        as UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE,
+    USampler2DMSArray   as UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY,
+    USampler2DRect      as UNSIGNED_INT_SAMPLER_2D_RECT,
+    USampler2DCube      as UNSIGNED_INT_SAMPLER_CUBE,
+    USampler2DCubeArray as UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY,
+    USamplerBuffer      as UNSIGNED_INT_SAMPLER_BUFFER,
+}
@@ -155,8 +155,6 @@ pub mod signature {
 pub mod bounds {
     use glsl::sampler;
 
-    use crate::gl::texture;
-
     use super::*;
 
     pub trait TransparentUniform: Uniform + glsl::bounds::TransparentType + ops::Set { }
@@ -196,14 +194,30 @@ pub mod bounds {
 
     impl<T, O> Uniform for glsl::glsl::GSampler<T, O>
     where
-        T: texture::Target,
+        T: sampler::Target,
         O: sampler::Output,
     { }
 
     impl<T, O> OpaqueUniform for glsl::glsl::GSampler<T, O>
     where
-        T: texture::Target,
+        T: sampler::Target,
+        O: sampler::Output,
+    { }
+
+    impl<T, O, F, A> Uniform for glsl::GImage<T, O, F, A>
+    where
+        T: crate::gl::texture::Target,
+        O: sampler::Output,
+        F: glsl::image::FormatQualifier<Output = O>,
+        A: glsl::image::memory::Access,
+    { }
+
+    impl<T, O, F, A> OpaqueUniform for glsl::GImage<T, O, F, A>
+    where
+        T: crate::gl::texture::Target,
         O: sampler::Output,
+        F: glsl::image::FormatQualifier<Output = O>,
+        A: glsl::image::memory::Access,
     { }
 }
 
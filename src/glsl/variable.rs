@@ -113,6 +113,8 @@ pub type OutVariable<T, const LOCATION: usize, S = md::Phantom> = Variable<stora
 
 pub type SamplerVariable<Target, Output, const BINDING: usize> = OpaqueUniformVariable<glsl::GSampler<Target, Output>, BINDING>;
 
+pub type ImageVariable<Target, Output, Format, Access, const BINDING: usize> = OpaqueUniformVariable<glsl::GImage<Target, Output, Format, Access>, BINDING>;
+
 impl<T, const LOCATION: usize> OutVariable<T, LOCATION>
 where
     T: glsl::bounds::TransparentType,